@@ -0,0 +1,61 @@
+//! Generates a test per example input in `data/`, see `tests/examples.rs`.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+#[path = "src/expected.rs"]
+mod expected;
+
+use expected::ExpectedFile;
+
+fn main() {
+    println!("cargo:rerun-if-changed=data");
+    println!("cargo:rerun-if-changed=src/expected.rs");
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+    // Packaged without the examples, there is nothing to test
+    let Ok(entries) = fs::read_dir("data") else {
+        fs::write(out, "").unwrap();
+        return;
+    };
+    let mut examples: Vec<(u8, String)> = entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            // `puzzle-N-SUFFIX.txt`, the real inputs having no suffix
+            let (day, _) = name
+                .strip_prefix("puzzle-")?
+                .strip_suffix(".txt")?
+                .split_once('-')?;
+            Some((day.parse().ok()?, name))
+        })
+        .collect();
+    examples.sort();
+
+    let mut tests = String::new();
+    for (day, name) in examples {
+        let stem = name.trim_end_matches(".txt");
+        let test = stem.replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+        let path = Path::new("data").join(format!("{stem}.expected"));
+        let expected = match fs::read_to_string(&path) {
+            Ok(text) => {
+                ExpectedFile::parse(&text).unwrap_or_else(|e| panic!("`{stem}.expected` {e}"))
+            }
+            Err(_) => ExpectedFile::default(),
+        };
+        let params = &expected.params;
+        // Like the stubs of `aoc new`, before the answers are known
+        if expected.answers.is_empty() {
+            writeln!(
+                tests,
+                "#[test]\nfn {test}_parse() {{\n    check({day}, {name:?}, None, &{params:?});\n}}\n"
+            )
+            .unwrap();
+        }
+        for (part, answer) in &expected.answers {
+            writeln!(
+                tests,
+                "#[test]\nfn {test}_part{part}() {{\n    check({day}, {name:?}, Some(({part}, {answer:?})), &{params:?});\n}}\n"
+            )
+            .unwrap();
+        }
+    }
+    fs::write(out, tests).unwrap();
+}
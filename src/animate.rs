@@ -0,0 +1,120 @@
+//! Frame by frame replay of the puzzles that simulate something, for
+//! `aoc run --animate`.
+
+use crate::render::Scene;
+
+/// A puzzle state advancing one step at a time.
+pub trait Simulation: Clone {
+    /// Advances one step, or returns `false` leaving the state as is once
+    /// the simulation is over.
+    fn step(&mut self) -> bool;
+    fn scene(&self) -> Scene;
+}
+
+/// The frames of a simulation, which can be visited in any order.
+pub trait Frames {
+    /// Index of the current frame, the first being 0.
+    fn frame(&self) -> usize;
+    /// Index of the last frame, once it has been reached.
+    fn last(&self) -> Option<usize>;
+    /// Moves to `frame`, or to the last one if there are fewer, and returns
+    /// the frame moved to.
+    fn seek(&mut self, frame: usize) -> usize;
+    fn scene(&self) -> Scene;
+}
+
+/// [`Frames`] of a [`Simulation`], going back by replaying from the
+/// closest of the states kept every [`Replay::CHECKPOINT`] frames.
+pub struct Replay<S> {
+    current: S,
+    frame: usize,
+    checkpoints: Vec<S>,
+    last: Option<usize>,
+}
+
+impl<S: Simulation> Replay<S> {
+    const CHECKPOINT: usize = 100;
+
+    pub fn new(start: S) -> Self {
+        Replay {
+            checkpoints: vec![start.clone()],
+            current: start,
+            frame: 0,
+            last: None,
+        }
+    }
+}
+
+impl<S: Simulation> Frames for Replay<S> {
+    fn frame(&self) -> usize {
+        self.frame
+    }
+
+    fn last(&self) -> Option<usize> {
+        self.last
+    }
+
+    fn seek(&mut self, frame: usize) -> usize {
+        let frame = self.last.map_or(frame, |last| frame.min(last));
+        if frame < self.frame {
+            let checkpoint = frame / Self::CHECKPOINT;
+            self.current = self.checkpoints[checkpoint].clone();
+            self.frame = checkpoint * Self::CHECKPOINT;
+        }
+        while self.frame < frame {
+            if !self.current.step() {
+                self.last = Some(self.frame);
+                break;
+            }
+            self.frame += 1;
+            if self.frame == self.checkpoints.len() * Self::CHECKPOINT {
+                self.checkpoints.push(self.current.clone());
+            }
+        }
+        self.frame
+    }
+
+    fn scene(&self) -> Scene {
+        self.current.scene()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    /// Counts up to 250.
+    #[derive(Clone)]
+    struct Counter(usize);
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            self.0 += 1;
+            if self.0 > 250 {
+                self.0 -= 1;
+                return false;
+            }
+            true
+        }
+
+        fn scene(&self) -> Scene {
+            Scene::new(&Grid::new(1, 1, self.0), |_| '.')
+        }
+    }
+
+    #[test]
+    fn test_seek() {
+        let mut replay = Replay::new(Counter(0));
+        assert_eq!(replay.seek(120), 120);
+        assert_eq!(replay.current.0, 120);
+        assert_eq!(replay.seek(7), 7);
+        assert_eq!(replay.current.0, 7);
+        assert_eq!(replay.last(), None);
+        assert_eq!(replay.seek(1000), 250);
+        assert_eq!(replay.last(), Some(250));
+        assert_eq!(replay.seek(201), 201);
+        assert_eq!(replay.current.0, 201);
+        assert_eq!(replay.checkpoints.len(), 3);
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt::Display;
+
+use crate::Coord;
+
+/// What a part returns, formatted the same way whatever the day.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    /// Integers out of the range of `Int`.
+    BigInt(i128),
+    Str(String),
+    /// Written `x,y`, like the puzzles ask for positions.
+    Coord(Coord),
+    /// Written comma-separated, like puzzle 17's output.
+    List(Vec<i64>),
+}
+
+impl Answer {
+    /// Name of the variant, for machine-readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Int(_) => "int",
+            Answer::BigInt(_) => "bigint",
+            Answer::Str(_) => "string",
+            Answer::Coord(_) => "coord",
+            Answer::List(_) => "list",
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::BigInt(n) => write!(f, "{n}"),
+            Answer::Str(s) => f.write_str(s),
+            Answer::Coord((x, y)) => write!(f, "{x},{y}"),
+            Answer::List(values) => {
+                let values: Vec<_> = values.iter().map(i64::to_string).collect();
+                f.write_str(&values.join(","))
+            }
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        i64::try_from(n).map_or(Answer::BigInt(n), Answer::Int)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        Answer::from(i128::try_from(n).expect("answer to fit in an i128"))
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Int(n)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::from(i128::from(n))
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::from(n as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_owned())
+    }
+}
+
+impl From<Coord> for Answer {
+    fn from(c: Coord) -> Self {
+        Answer::Coord(c)
+    }
+}
+
+impl<T: Into<i64>> From<Vec<T>> for Answer {
+    fn from(values: Vec<T>) -> Self {
+        Answer::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+/// `none` for inputs the part has no answer for, like a program that no
+/// value of A makes print itself.
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Self {
+        answer.map_or_else(|| Answer::from("none"), Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42u64).to_string(), "42");
+        assert_eq!(Answer::from(u64::MAX).kind(), "bigint");
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(Answer::from((6, 1)).to_string(), "6,1");
+        assert_eq!(Answer::from(vec![4u8, 6, 3]).to_string(), "4,6,3");
+        assert_eq!(Answer::from(Vec::<u8>::new()).to_string(), "");
+        assert_eq!(Answer::from(Some(7u64)), Answer::Int(7));
+        assert_eq!(Answer::from(None::<u64>).to_string(), "none");
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    fs::File,
+    io::{self, IsTerminal, Read, Write},
+    process::Command,
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_2024::animate::Frames;
+
+/// Frames skipped by `[` and `]`.
+const SEEK: usize = 100;
+/// How often keys are read while paused or between slow frames.
+const POLL: Duration = Duration::from_millis(20);
+
+pub const KEYS: &str =
+    "space pause, n/→ next, p/← previous, ]/[ seek, 0 restart, +/- speed, q quit";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Key {
+    Pause,
+    Next,
+    Previous,
+    Forward,
+    Back,
+    Restart,
+    Faster,
+    Slower,
+    Quit,
+}
+
+/// The keys in what was typed, arrows being escape sequences.
+fn keys(mut typed: &[u8]) -> Vec<Key> {
+    let mut keys = Vec::new();
+    while let Some(&b) = typed.first() {
+        let (key, len) = match (b, typed.get(1..3)) {
+            (0x1b, Some(b"[C")) => (Some(Key::Next), 3),
+            (0x1b, Some(b"[D")) => (Some(Key::Previous), 3),
+            (0x1b, Some([b'[', _])) => (None, 3),
+            (b' ', _) => (Some(Key::Pause), 1),
+            (b'n', _) => (Some(Key::Next), 1),
+            (b'p', _) => (Some(Key::Previous), 1),
+            (b']', _) => (Some(Key::Forward), 1),
+            (b'[', _) => (Some(Key::Back), 1),
+            (b'0', _) => (Some(Key::Restart), 1),
+            (b'+' | b'=', _) => (Some(Key::Faster), 1),
+            (b'-', _) => (Some(Key::Slower), 1),
+            // Ctrl-C comes as a byte, signals being off
+            (b'q' | 0x03 | 0x1b, _) => (Some(Key::Quit), 1),
+            _ => (None, 1),
+        };
+        keys.extend(key);
+        typed = &typed[len..];
+    }
+    keys
+}
+
+/// The terminal in raw mode, for keys to be read as typed and without
+/// blocking, until dropped.
+struct RawTerminal {
+    tty: File,
+    saved: String,
+}
+
+impl RawTerminal {
+    /// `None` without a terminal to read keys from.
+    fn new() -> Option<Self> {
+        let tty = File::open("/dev/tty").ok()?;
+        let saved = stty(&tty, &["-g"])?;
+        stty(
+            &tty,
+            &["-icanon", "-echo", "-isig", "min", "0", "time", "0"],
+        )?;
+        Some(RawTerminal {
+            tty,
+            saved: saved.trim().to_owned(),
+        })
+    }
+
+    fn keys(&mut self) -> Vec<Key> {
+        let mut buf = [0; 64];
+        match self.tty.read(&mut buf) {
+            Ok(n) => keys(&buf[..n]),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        stty(&self.tty, &[&self.saved]);
+    }
+}
+
+fn stty(tty: &File, args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(tty.try_clone().ok()?)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Plays `frames` at `fps` frames per second, controlled from the keyboard
+/// if there is a terminal. Otherwise every frame is printed once, in order.
+pub fn play(frames: &mut dyn Frames, title: &str, mut fps: f64) -> io::Result<()> {
+    let interactive = io::stdout().is_terminal();
+    let mut terminal = if interactive {
+        RawTerminal::new()
+    } else {
+        None
+    };
+    let mut out = io::stdout().lock();
+    let mut paused = false;
+    let mut next = Instant::now();
+    let mut drawn = None;
+    if interactive {
+        write!(out, "\x1b[?25l")?;
+    }
+    let res = loop {
+        let typed = terminal.as_mut().map_or_else(Vec::new, RawTerminal::keys);
+        if typed.contains(&Key::Quit) {
+            break Ok(());
+        }
+        for key in typed {
+            control(frames, key, &mut paused, &mut fps);
+        }
+        let now = Instant::now();
+        if !paused && now >= next {
+            let frame = frames.frame();
+            // The first frame is shown before moving on
+            if drawn.is_some() && frames.seek(frame + 1) == frame {
+                if terminal.is_none() {
+                    break Ok(());
+                }
+                paused = true;
+            }
+            next = now + Duration::from_secs_f64(1.0 / fps);
+        }
+        let state = (frames.frame(), paused, fps.to_bits());
+        if drawn != Some(state) {
+            if let Err(e) = draw(&mut out, frames, title, paused, fps, interactive) {
+                break Err(e);
+            }
+            drawn = Some(state);
+        }
+        let until_next = next.saturating_duration_since(Instant::now());
+        thread::sleep(if paused { POLL } else { until_next.min(POLL) });
+    };
+    if interactive {
+        write!(out, "\x1b[?25h")?;
+    }
+    res
+}
+
+fn control(frames: &mut dyn Frames, key: Key, paused: &mut bool, fps: &mut f64) {
+    let frame = frames.frame();
+    match key {
+        Key::Pause => *paused = !*paused,
+        Key::Next | Key::Previous => {
+            *paused = true;
+            frames.seek(match key {
+                Key::Next => frame + 1,
+                _ => frame.saturating_sub(1),
+            });
+        }
+        Key::Forward => _ = frames.seek(frame + SEEK),
+        Key::Back => _ = frames.seek(frame.saturating_sub(SEEK)),
+        Key::Restart => _ = frames.seek(0),
+        Key::Faster => *fps *= 2.0,
+        Key::Slower => *fps /= 2.0,
+        Key::Quit => (),
+    }
+}
+
+fn draw(
+    out: &mut impl Write,
+    frames: &dyn Frames,
+    title: &str,
+    paused: bool,
+    fps: f64,
+    interactive: bool,
+) -> io::Result<()> {
+    if interactive {
+        write!(out, "\x1b[H\x1b[2J")?;
+    }
+    writeln!(out, "{}", frames.scene().draw(interactive))?;
+    let last = frames.last().map_or("?".to_owned(), |l| l.to_string());
+    let state = if paused { "paused" } else { "playing" };
+    writeln!(
+        out,
+        "{title}, frame {}/{last}, {fps} fps, {state}",
+        frames.frame()
+    )?;
+    if interactive {
+        writeln!(out, "{KEYS}")?;
+    }
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(
+            keys(b" n\x1b[D\x1b[A]q"),
+            [
+                Key::Pause,
+                Key::Next,
+                Key::Previous,
+                Key::Forward,
+                Key::Quit
+            ]
+        );
+        assert_eq!(keys(b"\x1b"), [Key::Quit]);
+    }
+}
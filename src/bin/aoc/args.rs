@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+/// Command line split into positional arguments and `--name value` options.
+pub struct Args {
+    positional: Vec<String>,
+    options: Vec<(String, Option<String>)>,
+}
+
+impl Args {
+    /// `flags` lists the options that never take a value, like `--all`.
+    pub fn parse(args: impl IntoIterator<Item = String>, flags: &[&str]) -> Result<Self, String> {
+        let mut positional = Vec::new();
+        let mut options = Vec::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                positional.push(arg);
+                continue;
+            };
+            if let Some((name, value)) = name.split_once('=') {
+                options.push((name.to_owned(), Some(value.to_owned())));
+            } else if flags.contains(&name) {
+                options.push((name.to_owned(), None));
+            } else {
+                let Some(value) = args.next() else {
+                    Err(format!("Missing value for `--{name}`"))?
+                };
+                options.push((name.to_owned(), Some(value)));
+            }
+        }
+        Ok(Args {
+            positional,
+            options,
+        })
+    }
+
+    pub fn positional(&self) -> &[String] {
+        &self.positional
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.iter().any(|(n, _)| n == name)
+    }
+
+    /// Last value given for `--name`, if any.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(n, _)| n == name)
+            .and_then(|(_, v)| v.as_deref())
+    }
+
+    /// Every value given for a repeatable `--name`, in order.
+    pub fn values<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> {
+        self.options
+            .iter()
+            .filter(move |(n, _)| n == name)
+            .filter_map(|(_, v)| v.as_deref())
+    }
+
+    pub fn parsed<T: FromStr>(&self, name: &str) -> Result<Option<T>, String>
+    where
+        T::Err: ToString,
+    {
+        self.value(name)
+            .map(|v| {
+                v.parse()
+                    .map_err(|e: T::Err| format!("Invalid `--{name}`: {}", e.to_string()))
+            })
+            .transpose()
+    }
+
+    /// Rejects any option not in `known`, so typos do not go unnoticed.
+    pub fn only(&self, known: &[&str]) -> Result<(), String> {
+        match self
+            .options
+            .iter()
+            .find(|(n, _)| !known.contains(&n.as_str()))
+        {
+            Some((n, _)) => Err(format!("Unknown option `--{n}`")),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,359 @@
+//! `aoc batch`: runs a day on every input matching a pattern, each part in a
+//! child process so that panics and runaway solvers only fail their row.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use aoc_2024::Part;
+
+use crate::{
+    args::Args,
+    bench::format_duration,
+    child, day_arg,
+    output::csv_field,
+    params_arg, parts_arg,
+    verify::{self, DEFAULT_ANSWERS},
+    watch::Expected,
+    Error,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Report {
+    Markdown,
+    Csv,
+}
+
+impl FromStr for Report {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "markdown" => Ok(Report::Markdown),
+            "csv" => Ok(Report::Csv),
+            _ => Err(format!(
+                "REPORT must be one of `markdown` or `csv`, got `{s}`"
+            )),
+        }
+    }
+}
+
+/// How the run of a part on an input went.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Status {
+    /// An answer, with nothing recorded to check it against.
+    Solved,
+    Pass,
+    Fail,
+    Panic,
+    Timeout,
+    /// The solver refused the input, or the command failed.
+    Error,
+}
+
+impl Status {
+    fn name(&self) -> &'static str {
+        match self {
+            Status::Solved => "ok",
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Panic => "PANIC",
+            Status::Timeout => "TIMEOUT",
+            Status::Error => "ERROR",
+        }
+    }
+}
+
+struct Row {
+    input: String,
+    part: Part,
+    status: Status,
+    /// The answer, or what went wrong.
+    answer: String,
+    expected: String,
+    elapsed: Option<Duration>,
+}
+
+impl Row {
+    const HEADER: [&'static str; 6] = ["input", "part", "status", "answer", "expected", "time"];
+
+    fn values(&self) -> [String; 6] {
+        [
+            self.input.clone(),
+            self.part.to_string(),
+            self.status.name().to_owned(),
+            self.answer.clone(),
+            self.expected.clone(),
+            self.elapsed.map_or(String::new(), format_duration),
+        ]
+    }
+}
+
+/// Files matching `pattern`, sorted, whose file name may hold `*` for any
+/// characters and `?` for any one.
+fn glob(pattern: &str) -> Result<Vec<PathBuf>, String> {
+    let path = Path::new(pattern);
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        Err(format!("No file name in `{pattern}`"))?
+    };
+    if dir.to_string_lossy().contains(['*', '?']) {
+        Err(format!(
+            "Only file names may hold wildcards, not in `{pattern}`"
+        ))?
+    }
+    let listed = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+    let entries =
+        fs::read_dir(listed).map_err(|e| format!("Error reading `{}`: {e}", listed.display()))?;
+    let name: Vec<char> = name.chars().collect();
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| {
+            let file = entry.ok()?.file_name().into_string().ok()?;
+            matches(&name, &file.chars().collect::<Vec<_>>()).then(|| dir.join(file))
+        })
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+fn matches(pattern: &[char], name: &[char]) -> bool {
+    match (pattern.first(), name.first()) {
+        (None, None) => true,
+        (Some('*'), _) => {
+            matches(&pattern[1..], name) || (!name.is_empty() && matches(pattern, &name[1..]))
+        }
+        (Some(p), Some(n)) if *p == '?' || p == n => matches(&pattern[1..], &name[1..]),
+        _ => false,
+    }
+}
+
+/// The message of a panic, from the child's stderr.
+/// How a run that exited went, from what it printed: its answer checked
+/// against `expected`, else its panic or error.
+fn classify(row: &mut Row, expected: Option<&str>, stdout: &str, stderr: &str) {
+    if let Some(answer) = child::answers(stdout).into_iter().next() {
+        row.status = match expected {
+            Some(e) if e == answer.answer => Status::Pass,
+            Some(_) => Status::Fail,
+            None => Status::Solved,
+        };
+        (row.answer, row.elapsed) = (answer.answer, answer.elapsed);
+    } else if let Some(message) = panic_message(stderr) {
+        (row.status, row.answer) = (Status::Panic, message);
+    } else if let Some(error) = stderr.lines().next() {
+        row.status = match error.starts_with("TIMEOUT") {
+            true => Status::Timeout,
+            false => Status::Error,
+        };
+        row.answer = error.to_owned();
+    }
+}
+
+fn panic_message(stderr: &str) -> Option<String> {
+    let mut lines = stderr.lines().skip_while(|l| !l.contains("panicked at"));
+    let at = lines.next()?;
+    Some(lines.next().unwrap_or(at).to_owned())
+}
+
+/// Runs `part` of `day` on `input` in a child process, killed after
+/// `timeout`.
+fn run(
+    day: u8,
+    input: &Path,
+    part: Part,
+    expected: &Expected,
+    params: &[String],
+    threads: Option<usize>,
+    timeout: Duration,
+) -> Result<Row, Error> {
+    let exe = env::current_exe().map_err(|e| Error::Failed(format!("No `aoc` to run: {e}")))?;
+    let params = [&expected.params[..], params].concat();
+    // The budget is kept here, the child's own default would cut it short
+    let mut child = child::spawn(&exe, day, input, Some(part), &params, threads, None)
+        .map_err(|e| Error::Failed(format!("Error running `aoc`: {e}")))?;
+    let start = Instant::now();
+    let mut row = Row {
+        input: input.display().to_string(),
+        part,
+        status: Status::Timeout,
+        answer: String::new(),
+        expected: expected.answer(part).unwrap_or_default().to_owned(),
+        elapsed: None,
+    };
+    let Some(status) = child::wait(&mut child, child::POLL, || start.elapsed() > timeout) else {
+        row.answer = format!("over {}", format_duration(timeout));
+        return Ok(row);
+    };
+    let (stdout, stderr) = child::output(child);
+    (row.status, row.answer) = (Status::Error, format!("exited with {status}"));
+    classify(&mut row, expected.answer(part), &stdout, &stderr);
+    Ok(row)
+}
+
+fn markdown(rows: &[Row]) -> String {
+    let line = |cells: &[String]| format!("| {} |\n", cells.join(" | "));
+    let mut text = line(&Row::HEADER.map(str::to_owned));
+    text += &line(&Row::HEADER.map(|h| "-".repeat(h.len())));
+    for row in rows {
+        let mut cells = row.values().map(|c| c.replace('|', "\\|"));
+        // Problems stand out in bold
+        if !matches!(row.status, Status::Solved | Status::Pass) {
+            cells[2] = format!("**{}**", cells[2]);
+        }
+        text += &line(&cells);
+    }
+    text
+}
+
+fn csv(rows: &[Row]) -> String {
+    let mut text = Row::HEADER.join(",") + "\n";
+    for row in rows {
+        let cells: Vec<_> = row.values().iter().map(|c| csv_field(c)).collect();
+        text += &(cells.join(",") + "\n");
+    }
+    text
+}
+
+pub fn batch(args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let args = Args::parse(args, &[])?;
+    args.only(&[
+        "inputs", "part", "param", "report", "output", "timeout", "threads",
+    ])?;
+    let day = day_arg(args.positional())?;
+    let parts = parts_arg(&args)?;
+    // Checked here, passed on as given
+    params_arg(&args)?;
+    let params: Vec<String> = args.values("param").map(str::to_owned).collect();
+    let threads = args.parsed::<usize>("threads")?;
+    if threads == Some(0) {
+        Err("`--threads` must be at least 1")?
+    }
+    let Some(pattern) = args.value("inputs") else {
+        Err("Expected `--inputs GLOB`")?
+    };
+    let timeout = args.parsed::<f64>("timeout")?.unwrap_or(10.0);
+    if timeout.is_nan() || timeout <= 0.0 {
+        Err(format!("`--timeout` must be positive, got `{timeout}`"))?
+    }
+    let timeout = Duration::from_secs_f64(timeout);
+    let report = args.parsed::<Report>("report")?.unwrap_or(Report::Markdown);
+    let inputs = glob(pattern).map_err(Error::Failed)?;
+    if inputs.is_empty() {
+        Err(Error::Failed(format!("No file matches `{pattern}`")))?
+    }
+
+    let recorded = verify::load_answers(DEFAULT_ANSWERS).unwrap_or_default();
+    let mut rows = Vec::new();
+    for input in &inputs {
+        let expected = Expected::for_input(day.day, input, &recorded).map_err(Error::Failed)?;
+        for &part in &parts {
+            let row = run(day.day, input, part, &expected, &params, threads, timeout)?;
+            eprintln!("{} part {part}: {}", row.input, row.status.name());
+            rows.push(row);
+        }
+    }
+    let text = match report {
+        Report::Markdown => markdown(&rows),
+        Report::Csv => csv(&rows),
+    };
+    match args.value("output") {
+        Some(path) => {
+            fs::write(path, text)
+                .map_err(|e| Error::Failed(format!("Error writing `{path}`: {e}")))?;
+            eprintln!("Wrote `{path}`");
+        }
+        None => print!("{text}"),
+    }
+    let failed = rows
+        .iter()
+        .filter(|r| !matches!(r.status, Status::Solved | Status::Pass))
+        .count();
+    if failed > 0 {
+        Err(Error::Failed(format!(
+            "{failed} of {} run(s) failed, panicked or timed out",
+            rows.len()
+        )))?
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob() {
+        let m = |p: &str, n: &str| {
+            matches(
+                &p.chars().collect::<Vec<_>>(),
+                &n.chars().collect::<Vec<_>>(),
+            )
+        };
+        assert!(m("puzzle-10*.txt", "puzzle-10-test-1.txt"));
+        assert!(m("puzzle-10*.txt", "puzzle-10.txt"));
+        assert!(!m("puzzle-10*.txt", "puzzle-10-test.expected"));
+        assert!(m("a?c*", "abc"));
+        assert!(!m("a?c", "ac"));
+
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        let found = glob(&format!("{}/puzzle-10-test*.txt", dir.display())).unwrap();
+        let names: Vec<_> = found.iter().map(|p| p.file_name().unwrap()).collect();
+        assert_eq!(
+            names,
+            [
+                "puzzle-10-test-1.txt",
+                "puzzle-10-test-2.txt",
+                "puzzle-10-test.txt"
+            ]
+        );
+        assert!(glob("data/*/puzzle.txt").is_err());
+
+        let stderr = "\nthread 'main' panicked at src/x.rs:1:2:\nno way\nnote: run with\n";
+        assert_eq!(panic_message(stderr).as_deref(), Some("no way"));
+    }
+
+    #[test]
+    fn test_classify() {
+        let classify = |expected, stdout, stderr| {
+            let mut row = Row {
+                input: "in.txt".to_owned(),
+                part: Part::One,
+                status: Status::Error,
+                answer: "exited with 1".to_owned(),
+                expected: String::new(),
+                elapsed: None,
+            };
+            classify(&mut row, expected, stdout, stderr);
+            (row.status, row.answer)
+        };
+        let answer = "{\"day\":1,\"part\":1,\"answer\":\"42\",\"elapsed_ns\":5}\n";
+        assert_eq!(
+            classify(Some("42"), answer, ""),
+            (Status::Pass, "42".into())
+        );
+        assert_eq!(classify(Some("7"), answer, ""), (Status::Fail, "42".into()));
+        assert_eq!(classify(None, answer, ""), (Status::Solved, "42".into()));
+        // A solver still going after the child's own 60s default
+        let timeout = "TIMEOUT after 60s\n";
+        assert_eq!(
+            classify(None, "", timeout),
+            (Status::Timeout, "TIMEOUT after 60s".into())
+        );
+        let error = "Unknown parameter `x`\nmore\n";
+        assert_eq!(
+            classify(None, "", error),
+            (Status::Error, "Unknown parameter `x`".into())
+        );
+        assert_eq!(
+            classify(None, "", ""),
+            (Status::Error, "exited with 1".into())
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_2024::{params::Overrides, puzzles, Day, Part, RunError};
+
+use crate::{args::Args, day_arg, json::Value, params_arg, parts_arg, table, threads_arg, Error};
+
+const DEFAULT_BASELINE: &str = "target/aoc-bench.json";
+
+/// What one row of the report times.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => f.write_str("parse"),
+            Stage::Part(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Stats {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    stddev: Duration,
+}
+
+impl Stats {
+    fn new(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        let variance = samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Stats {
+            min: samples[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// What a baseline record is compared by: the same stage of the same day on
+/// the same input and parameters.
+struct Key {
+    day: u8,
+    stage: Stage,
+    input: String,
+    params: String,
+}
+
+impl Key {
+    fn new(day: u8, stage: Stage, input: &Path, params: &Overrides) -> Self {
+        let mut params: Vec<String> = params.iter().map(|(n, v)| format!("{n}={v}")).collect();
+        params.sort_unstable();
+        Key {
+            day,
+            stage,
+            input: input.display().to_string(),
+            params: params.join(","),
+        }
+    }
+
+    fn matches(&self, record: &Value) -> bool {
+        let text = |field| record.get(field).and_then(Value::as_str);
+        record.get("day").and_then(Value::as_f64) == Some(f64::from(self.day))
+            && text("stage") == Some(&self.stage.to_string())
+            && text("input") == Some(&self.input)
+            && text("params") == Some(&self.params)
+    }
+}
+
+/// A timed stage of a day, as saved in the baseline file.
+struct Record {
+    key: Key,
+    stats: Stats,
+}
+
+impl Record {
+    fn to_json(&self) -> Value {
+        let ns = |d: Duration| Value::from(d.as_nanos() as u64);
+        let key = &self.key;
+        Value::Object(vec![
+            ("day".to_owned(), Value::from(u64::from(key.day))),
+            ("stage".to_owned(), Value::from(key.stage.to_string())),
+            ("input".to_owned(), Value::from(key.input.as_str())),
+            ("params".to_owned(), Value::from(key.params.as_str())),
+            ("min_ns".to_owned(), ns(self.stats.min)),
+            ("median_ns".to_owned(), ns(self.stats.median)),
+            ("mean_ns".to_owned(), ns(self.stats.mean)),
+            ("stddev_ns".to_owned(), ns(self.stats.stddev)),
+        ])
+    }
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos();
+    match ns {
+        0..1_000 => format!("{ns}ns"),
+        1_000..1_000_000 => format!("{:.2}µs", ns as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.2}ms", ns as f64 / 1e6),
+        _ => format!("{:.2}s", d.as_secs_f64()),
+    }
+}
+
+/// Records of a baseline file, missing files being empty baselines.
+fn load_baseline(path: &Path) -> Result<Vec<Value>, Error> {
+    let Ok(text) = fs::read_to_string(path) else {
+        return Ok(Vec::new());
+    };
+    let json = Value::parse(&text)
+        .map_err(|e| Error::Failed(format!("Invalid baseline `{}`: {e}", path.display())))?;
+    Ok(json
+        .get("runs")
+        .and_then(Value::as_array)
+        .unwrap_or_default()
+        .to_vec())
+}
+
+fn baseline_median(baseline: &[Value], key: &Key) -> Option<f64> {
+    baseline
+        .iter()
+        .find(|r| key.matches(r))
+        .and_then(|r| r.get("median_ns")?.as_f64())
+}
+
+fn save_baseline(path: &Path, mut baseline: Vec<Value>, records: &[Record]) -> Result<(), Error> {
+    baseline.retain(|old| !records.iter().any(|r| r.key.matches(old)));
+    baseline.extend(records.iter().map(Record::to_json));
+    let lines: Vec<String> = baseline.iter().map(|r| format!("  {r}")).collect();
+    let text = format!("{{\"runs\":[\n{}\n]}}\n", lines.join(",\n"));
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).map_err(|e| RunError::Io(dir.to_owned(), e))?;
+    }
+    fs::write(path, text).map_err(|e| RunError::Io(path.to_owned(), e))?;
+    eprintln!("Saved baseline to `{}`", path.display());
+    Ok(())
+}
+
+/// Times `runs` runs of `day` after `warmup` untimed ones.
+fn bench_day(
+    day: &Day,
+    input: &Path,
+    parts: &[Part],
+    params: &Overrides,
+    warmup: usize,
+    runs: usize,
+) -> Result<Vec<(Stage, Stats)>, RunError> {
+    eprintln!("Benchmarking day {}...", day.day);
+    for _ in 0..warmup {
+        day.time(input, parts, params)?;
+    }
+    let mut samples = vec![Vec::with_capacity(runs); parts.len() + 1];
+    for _ in 0..runs {
+        let timing = day.time(input, parts, params)?;
+        samples[0].push(timing.parse);
+        for (i, (_, elapsed)) in timing.parts.into_iter().enumerate() {
+            samples[i + 1].push(elapsed);
+        }
+    }
+    let stages = [Stage::Parse]
+        .into_iter()
+        .chain(parts.iter().copied().map(Stage::Part));
+    Ok(stages
+        .zip(samples.iter_mut())
+        .map(|(stage, samples)| (stage, Stats::new(samples)))
+        .collect())
+}
+
+pub fn bench(args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let args = Args::parse(args, &["save"])?;
+    args.only(&[
+        "part",
+        "input",
+        "param",
+        "runs",
+        "warmup",
+        "baseline",
+        "save",
+        "threshold",
+        "threads",
+    ])?;
+    let parts = parts_arg(&args)?;
+    threads_arg(&args)?;
+    let params = params_arg(&args)?;
+    let runs = args.parsed::<usize>("runs")?.unwrap_or(10);
+    let warmup = args.parsed::<usize>("warmup")?.unwrap_or(1);
+    let threshold = args.parsed::<f64>("threshold")?.unwrap_or(10.0);
+    let baseline_path = PathBuf::from(args.value("baseline").unwrap_or(DEFAULT_BASELINE));
+    if runs == 0 {
+        Err("`--runs` must be at least 1")?
+    }
+    let days: Vec<&Day> = if args.positional().is_empty() {
+        if args.value("input").is_some() || !params.is_empty() {
+            Err("`--input` and `--param` need a DAY")?
+        }
+        puzzles::ALL.iter().collect()
+    } else {
+        vec![day_arg(args.positional())?]
+    };
+
+    let baseline = load_baseline(&baseline_path)?;
+    let mut records = Vec::new();
+    let mut rows = Vec::new();
+    let mut regressions = 0;
+    for day in days {
+        let input = args
+            .value("input")
+            .map_or_else(|| day.default_input(), PathBuf::from);
+        let stages = match bench_day(day, &input, &parts, &params, warmup, runs) {
+            Ok(stages) => stages,
+            // One broken day should not hide the timings of the others
+            Err(e) if args.positional().is_empty() => {
+                eprintln!("Skipping day {}: {e}", day.day);
+                continue;
+            }
+            Err(e) => Err(e)?,
+        };
+        for (stage, stats) in stages {
+            let key = Key::new(day.day, stage, &input, &params);
+            let change = match baseline_median(&baseline, &key) {
+                Some(old) if old > 0.0 => {
+                    let pct = (stats.median.as_nanos() as f64 / old - 1.0) * 100.0;
+                    if pct > threshold {
+                        regressions += 1;
+                        format!("{pct:+.1}% REGRESSION")
+                    } else {
+                        format!("{pct:+.1}%")
+                    }
+                }
+                _ => String::new(),
+            };
+            rows.push(vec![
+                day.day.to_string(),
+                stage.to_string(),
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.mean),
+                format_duration(stats.stddev),
+                change,
+            ]);
+            records.push(Record { key, stats });
+        }
+    }
+    table::print(
+        &[
+            "Day",
+            "Stage",
+            "Min",
+            "Median",
+            "Mean",
+            "Std dev",
+            "vs baseline",
+        ],
+        &rows,
+    );
+
+    if args.flag("save") {
+        save_baseline(&baseline_path, baseline, &records)?;
+    }
+    if regressions > 0 {
+        Err(Error::Failed(format!(
+            "{regressions} median(s) regressed by more than {threshold}%"
+        )))?
+    }
+    Ok(())
+}
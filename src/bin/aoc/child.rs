@@ -0,0 +1,125 @@
+//! Runs of `aoc run` in a child process, for the commands that must survive
+//! a solver panicking or never returning.
+
+use std::{
+    io::{self, Read},
+    path::Path,
+    process::{Child, Command, ExitStatus, Stdio},
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use aoc_2024::Part;
+
+use crate::json::Value;
+
+/// How often [`wait`] checks on the child.
+pub const POLL: Duration = Duration::from_millis(10);
+
+/// An answer read back from the child's JSON output.
+pub struct Answer {
+    pub answer: String,
+    pub elapsed: Option<Duration>,
+}
+
+/// A started child, whose output is read as it comes: left in the pipes, more
+/// than they hold would block the child.
+pub struct Running {
+    child: Child,
+    stdout: JoinHandle<String>,
+    stderr: JoinHandle<String>,
+}
+
+fn read_all(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut text = String::new();
+        if let Some(mut pipe) = pipe {
+            _ = pipe.read_to_string(&mut text);
+        }
+        text
+    })
+}
+
+/// Starts `exe run` on `input`, for both parts unless `part` is given, its
+/// answers printed as JSON. `params` are passed as `--param`, `threads` as
+/// `--threads` and `timeout` as `--timeout`, `None` lifting the child's own
+/// limit for callers enforcing theirs.
+pub fn spawn(
+    exe: &Path,
+    day: u8,
+    input: &Path,
+    part: Option<Part>,
+    params: &[String],
+    threads: Option<usize>,
+    timeout: Option<Duration>,
+) -> io::Result<Running> {
+    let mut aoc = Command::new(exe);
+    aoc.args(["run", &day.to_string(), "--format", "json", "--input"])
+        .arg(input);
+    if let Some(part) = part {
+        aoc.args(["--part", &part.to_string()]);
+    }
+    for param in params {
+        aoc.args(["--param", param]);
+    }
+    if let Some(threads) = threads {
+        aoc.args(["--threads", &threads.to_string()]);
+    }
+    let timeout = timeout.map_or(0.0, |t| t.as_secs_f64());
+    aoc.args(["--timeout", &timeout.to_string()]);
+    let mut child = aoc
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    Ok(Running {
+        stdout: read_all(child.stdout.take()),
+        stderr: read_all(child.stderr.take()),
+        child,
+    })
+}
+
+/// Waits for the child to exit, checking `give_up` every `poll`: the child
+/// is then killed and `None` returned.
+pub fn wait(
+    running: &mut Running,
+    poll: Duration,
+    mut give_up: impl FnMut() -> bool,
+) -> Option<ExitStatus> {
+    let child = &mut running.child;
+    loop {
+        if let Some(status) = child.try_wait().ok().flatten() {
+            return Some(status);
+        }
+        if give_up() {
+            _ = child.kill();
+            _ = child.wait();
+            return None;
+        }
+        thread::sleep(poll);
+    }
+}
+
+/// What the child printed on stdout and stderr, once it exited.
+pub fn output(running: Running) -> (String, String) {
+    let read = |pipe: JoinHandle<String>| pipe.join().unwrap_or_default();
+    (read(running.stdout), read(running.stderr))
+}
+
+pub fn answers(stdout: &str) -> Vec<Answer> {
+    stdout
+        .lines()
+        .filter_map(|l| Value::parse(l).ok())
+        .map(|record| Answer {
+            answer: record
+                .get("answer")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_owned(),
+            elapsed: record
+                .get("elapsed_ns")
+                .and_then(Value::as_f64)
+                .map(|ns| Duration::from_nanos(ns as u64)),
+        })
+        .collect()
+}
@@ -0,0 +1,71 @@
+use std::{fs, path::PathBuf};
+
+use aoc_2024::{
+    animate::Frames,
+    image::{self, Format, Palette},
+    render::Scene,
+};
+
+use crate::args::Args;
+
+/// Where and how `--export` writes images.
+pub struct Export {
+    dir: PathBuf,
+    format: Format,
+    scale: usize,
+    palette: Palette,
+}
+
+impl Export {
+    pub const OPTIONS: [&'static str; 4] = ["export", "image", "scale", "palette"];
+
+    /// `None` without `--export`, the other options then being refused.
+    pub fn from_args(args: &Args) -> Result<Option<Self>, String> {
+        let Some(dir) = args.value("export") else {
+            if let Some(option) = Self::OPTIONS.iter().find(|o| args.value(o).is_some()) {
+                Err(format!("`--{option}` only goes with `--export`"))?
+            }
+            return Ok(None);
+        };
+        let scale = args.parsed("scale")?.unwrap_or(4);
+        if scale == 0 {
+            Err("`--scale` must be positive")?
+        }
+        Ok(Some(Export {
+            dir: PathBuf::from(dir),
+            format: args.parsed("image")?.unwrap_or(Format::Ppm),
+            scale,
+            palette: args.parsed("palette")?.unwrap_or_default(),
+        }))
+    }
+
+    /// Writes `scene` to `NAME.ppm` or `NAME.pbm` in the directory.
+    pub fn scene(&self, scene: &Scene, name: &str) -> Result<PathBuf, String> {
+        fs::create_dir_all(&self.dir)
+            .map_err(|e| format!("Error creating `{}`: {e}", self.dir.display()))?;
+        let path = self.dir.join(format!("{name}.{}", self.format.extension()));
+        fs::write(
+            &path,
+            image::encode(scene, self.format, self.scale, &self.palette),
+        )
+        .map_err(|e| format!("Error writing `{}`: {e}", path.display()))?;
+        Ok(path)
+    }
+
+    /// Writes every frame from the current one on, as `NAME-00000` and so on,
+    /// and returns how many were written.
+    pub fn frames(&self, frames: &mut dyn Frames, name: &str) -> Result<usize, String> {
+        let first = frames.frame();
+        loop {
+            let frame = frames.frame();
+            self.scene(&frames.scene(), &format!("{name}-{frame:05}"))?;
+            if frames.seek(frame + 1) == frame {
+                return Ok(frame + 1 - first);
+            }
+        }
+    }
+
+    pub fn dir(&self) -> &PathBuf {
+        &self.dir
+    }
+}
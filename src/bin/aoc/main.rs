@@ -0,0 +1,383 @@
+mod animate;
+mod args;
+mod batch;
+mod bench;
+mod child;
+mod export;
+mod json;
+mod new;
+mod output;
+mod site;
+mod table;
+mod toml;
+mod verify;
+mod watch;
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_2024::{
+    log, parallel,
+    params::{self, Overrides},
+    puzzles, Day, Part, RunError,
+};
+use args::Args;
+use export::Export;
+use output::{Format, Record};
+
+const USAGE: &str = "USAGE: aoc run DAY [--part PART] [--input FILE] [--param NAME=VALUE]...
+                 [--format FORMAT] [--timeout SECONDS] [--render]
+                 [--animate [--fps FPS]]
+                 [--export DIR [--image IMAGE] [--scale N] [--palette PALETTE]]
+       aoc run --all [--part PART] [--format FORMAT] [--timeout SECONDS]
+       aoc bench [DAY] [--part PART] [--input FILE] [--param NAME=VALUE]...
+                 [--runs N] [--warmup N] [--baseline JSON] [--save]
+                 [--threshold PERCENT]
+       aoc verify [DAY] [--part PART] [--answers TOML] [--timeout SECONDS]
+       aoc gen DAY [--seed SEED] [--size N]
+       aoc fetch DAY [--force] [--config CONFIG]
+       aoc submit DAY PART [ANSWER | --input FILE [--param NAME=VALUE]...]
+                  [--config CONFIG]
+       aoc new DAY
+       aoc watch DAY [--poll MS] [--timeout SECONDS]
+       aoc batch DAY --inputs GLOB [--part PART] [--param NAME=VALUE]...
+                 [--report REPORT] [--output FILE] [--timeout SECONDS]
+\tWhere PART is one of `1` or `2` (both by default)
+\tand FILE defaults to `data/puzzle-DAY.txt`, `-` reading stdin.
+\t`--param` overrides a parameter of the day, like `size=11x7` for day 14.
+\tFORMAT is one of `text` (by default), `json` or `csv`.
+\t`--timeout` stops a part after SECONDS (60), reporting it as `TIMEOUT`,
+\t`0` meaning no limit.
+\t`--render` draws the map each part ends on, for days 14 to 16, 18 and 20.
+\t`--animate` replays days 6, 14, 15 and 18 at FPS (10) frames per second,
+\tspace pausing, n/p stepping, ]/[ seeking 100 frames and q quitting.
+\t`--export` writes those maps, or every frame with `--animate`, to DIR as
+\tIMAGE `ppm` (by default) or `pbm` with N (4) pixels per cell, PALETTE
+\tcoloring glyphs like `#=000000,O=00ff00`.
+\t`bench` compares medians to the JSON baseline (`target/aoc-bench.json`
+\tby default), failing above PERCENT (10) slower; `--save` updates it.
+\t`verify` checks the answers recorded in TOML (`answers.toml` by default),
+\tfailing if one is wrong or missing for a real input.
+\t`gen` prints a random input seeded by SEED (0), N (50) scaling it like
+\tthe number of lines or the side of the map.
+\t`fetch` saves the input to `data/puzzle-DAY.txt` unless already there,
+\tand `submit` sends ANSWER, or the one solved on FILE. CONFIG
+\t(`.aoc.toml` by default) sets `session` to the site's session cookie,
+\tand may set `base_url`, `year`, `user_agent` and `interval`, the seconds
+\tbetween requests (5). `https` goes through `curl`.
+\t`new` adds a day to `src/puzzles` with stubs to fill, an empty example
+\tand input in `data/`, never overwriting an existing day.
+\t`watch` rebuilds and runs the day on its examples and input whenever
+\tthey or its source change, checking every MS (500) milliseconds, and
+\tcompares the answers to the `.expected` files and `answers.toml`,
+\tstopping parts after SECONDS (10).
+\t`batch` runs each part on every file GLOB matches, like
+\t`'data/puzzle-10*.txt'`, as a REPORT table, `markdown` (by default) or
+\t`csv`, of answers and times, written to FILE or stdout. Parts panicking
+\tor taking over SECONDS (10) are reported as `PANIC` or `TIMEOUT`.
+\tAny command takes `--threads N`, days 6, 7, 13 and 19 then trying their
+\tcandidates on N (1) threads, and `-v`, repeated for more diagnostics on
+\tstderr (info, debug then trace), or `AOC_LOG` like `debug` or
+\t`info,day12=trace`.";
+
+fn main() -> ExitCode {
+    let (verbosity, args): (Vec<_>, Vec<_>) = env::args().skip(1).partition(|a| is_verbose(a));
+    let res = init_log(verbosity.iter().map(|v| v.len() - 1).sum()).and_then(|()| command(args));
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(Error::Usage(e)) => {
+            eprintln!("{e}\n{USAGE}");
+            ExitCode::FAILURE
+        }
+        Err(Error::Run(e)) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+        Err(Error::Failed(e)) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn command(args: Vec<String>) -> Result<(), Error> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("run") => run(args),
+        Some("bench") => bench::bench(args),
+        Some("verify") => verify::verify(args),
+        Some("gen") => gen(args),
+        Some("fetch") => site::fetch(args),
+        Some("submit") => site::submit(args),
+        Some("new") => new::new(args),
+        Some("watch") => watch::watch(args),
+        Some("batch") => batch::batch(args),
+        Some(cmd) => Err(format!("Unknown command `{cmd}`").into()),
+        None => Err("Not enough arguments".into()),
+    }
+}
+
+/// Bad command lines get the usage printed, failed runs only their error.
+enum Error {
+    Usage(String),
+    Run(RunError),
+    /// The command ran but its outcome is a failure, like a regression.
+    Failed(String),
+}
+
+impl From<String> for Error {
+    fn from(e: String) -> Self {
+        Error::Usage(e)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(e: &str) -> Self {
+        Error::Usage(e.to_owned())
+    }
+}
+
+impl From<RunError> for Error {
+    fn from(e: RunError) -> Self {
+        Error::Run(e)
+    }
+}
+
+/// `-v`, `-vv`…, accepted anywhere on the command line.
+fn is_verbose(arg: &str) -> bool {
+    arg.strip_prefix('-')
+        .is_some_and(|v| !v.is_empty() && v.chars().all(|c| c == 'v'))
+}
+
+fn init_log(verbosity: usize) -> Result<(), Error> {
+    let filter = match env::var("AOC_LOG") {
+        Ok(spec) => spec
+            .parse::<log::Filter>()
+            .map_err(|e| format!("Invalid `AOC_LOG`: {e}"))?,
+        Err(_) => log::Filter::default(),
+    };
+    log::init(filter.verbose(verbosity));
+    Ok(())
+}
+
+fn day_arg(positional: &[String]) -> Result<&'static Day, String> {
+    let n = day_number(positional)?;
+    puzzles::get(n).ok_or_else(|| format!("No solution for day {n}"))
+}
+
+/// The DAY argument, whether the day is solved or not.
+fn day_number(positional: &[String]) -> Result<u8, String> {
+    let [day] = positional else {
+        Err("Expected exactly one DAY")?
+    };
+    day.parse()
+        .map_err(|_| format!("DAY must be a number, got `{day}`"))
+}
+
+fn parts_arg(args: &Args) -> Result<Vec<Part>, String> {
+    Ok(match args.parsed::<Part>("part")? {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    })
+}
+
+fn params_arg(args: &Args) -> Result<Vec<(String, String)>, String> {
+    args.values("param").map(params::parse_override).collect()
+}
+
+/// Sets how many threads the parallel searches use, from `--threads`.
+fn threads_arg(args: &Args) -> Result<(), String> {
+    let threads = args.parsed::<usize>("threads")?.unwrap_or(1);
+    if threads == 0 {
+        Err("`--threads` must be at least 1")?
+    }
+    parallel::set_threads(threads);
+    Ok(())
+}
+
+/// The budget of each part from `--timeout`, in seconds, `0` lifting it.
+fn timeout_arg(args: &Args) -> Result<Option<Duration>, String> {
+    let timeout = args.parsed::<f64>("timeout")?.unwrap_or(60.0);
+    if timeout.is_nan() || timeout < 0.0 {
+        Err(format!(
+            "`--timeout` must be 0 (no limit) or positive, got `{timeout}`"
+        ))?
+    }
+    Ok((timeout > 0.0).then(|| Duration::from_secs_f64(timeout)))
+}
+
+fn run(args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let args = Args::parse(args, &["all", "render", "animate"])?;
+    args.only(
+        &[
+            &[
+                "all", "part", "input", "param", "format", "render", "animate", "fps", "threads",
+                "timeout",
+            ][..],
+            &Export::OPTIONS,
+        ]
+        .concat(),
+    )?;
+    let parts = parts_arg(&args)?;
+    threads_arg(&args)?;
+    let params = params_arg(&args)?;
+    let timeout = timeout_arg(&args)?;
+    let format = args.parsed::<Format>("format")?.unwrap_or(Format::Text);
+    let render = args.flag("render");
+    let animate = args.flag("animate");
+    let export = Export::from_args(&args)?;
+    if (render || animate || export.is_some()) && format != Format::Text {
+        Err("`--render`, `--animate` and `--export` only go with the `text` format")?
+    }
+    let fps = args.parsed::<f64>("fps")?;
+    if fps.is_some() && !animate {
+        Err("`--fps` only goes with `--animate`")?
+    }
+    if args.flag("all") {
+        if !args.positional().is_empty()
+            || args.value("input").is_some()
+            || !params.is_empty()
+            || render
+            || animate
+            || export.is_some()
+        {
+            Err("`--all` only takes `--part`, `--format` and `--timeout`")?
+        }
+        run_all(&parts, format, timeout);
+        return Ok(());
+    }
+
+    let day = day_arg(args.positional())?;
+    let input = args
+        .value("input")
+        .map_or_else(|| day.default_input(), PathBuf::from);
+    if animate {
+        let fps = fps.unwrap_or(10.0);
+        if fps.is_nan() || fps <= 0.0 {
+            Err(format!("`--fps` must be positive, got `{fps}`"))?
+        }
+        for part in parts {
+            let Some(mut frames) = day.animate(&input, part, &params)? else {
+                eprintln!("Nothing to animate for part {part} of day {}", day.day);
+                continue;
+            };
+            if let Some(export) = &export {
+                let name = format!("day{}-part{part}", day.day);
+                let count = export
+                    .frames(frames.as_mut(), &name)
+                    .map_err(Error::Failed)?;
+                eprintln!("Wrote {count} frames to `{}`", export.dir().display());
+                continue;
+            }
+            let title = format!("Day {} part {part}", day.day);
+            animate::play(frames.as_mut(), &title, fps)
+                .map_err(|e| Error::Failed(format!("Error drawing the animation: {e}")))?;
+        }
+        return Ok(());
+    }
+    if format == Format::Text {
+        for part in parts {
+            let res = day.solve_within(&input, part, &params, timeout)?;
+            println!("Got result `{res}` for part {part}!");
+            if !render && export.is_none() {
+                continue;
+            }
+            let Some(scene) = day.render_within(&input, part, &params, timeout)? else {
+                eprintln!("Nothing to draw for part {part} of day {}", day.day);
+                continue;
+            };
+            if render {
+                scene.print();
+            }
+            if let Some(export) = &export {
+                let path = export
+                    .scene(&scene, &format!("day{}-part{part}", day.day))
+                    .map_err(Error::Failed)?;
+                eprintln!("Wrote `{}`", path.display());
+            }
+        }
+        return Ok(());
+    }
+    let records = parts
+        .into_iter()
+        .map(|part| record(day, &input, part, &params, timeout))
+        .collect::<Result<Vec<_>, _>>()?;
+    output::print(format, &records);
+    Ok(())
+}
+
+fn record(
+    day: &Day,
+    input: &Path,
+    part: Part,
+    params: &Overrides,
+    timeout: Option<Duration>,
+) -> Result<Record, RunError> {
+    let hash = output::hash(input)?;
+    let start = Instant::now();
+    let answer = day.solve_within(input, part, params, timeout)?;
+    Ok(Record {
+        day: day.day,
+        part,
+        answer,
+        input: input.display().to_string(),
+        hash,
+        elapsed: start.elapsed(),
+    })
+}
+
+fn gen(args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let args = Args::parse(args, &[])?;
+    args.only(&["seed", "size"])?;
+    let day = day_arg(args.positional())?;
+    let seed = args.parsed::<u64>("seed")?.unwrap_or(0);
+    let size = args.parsed::<usize>("size")?.unwrap_or(50);
+    if size == 0 {
+        Err("`--size` must be at least 1")?
+    }
+    print!("{}", day.generate(seed, size));
+    Ok(())
+}
+
+fn run_all(parts: &[Part], format: Format, timeout: Option<Duration>) {
+    if format != Format::Text {
+        let mut records = Vec::new();
+        for day in &puzzles::ALL {
+            for &part in parts {
+                match record(day, &day.default_input(), part, &[], timeout) {
+                    Ok(record) => records.push(record),
+                    Err(e) => eprintln!("Skipping part {part} of day {}: {e}", day.day),
+                }
+            }
+        }
+        output::print(format, &records);
+        return;
+    }
+    let mut rows = Vec::new();
+    for day in &puzzles::ALL {
+        let input = day.default_input();
+        let mut row = vec![day.day.to_string()];
+        for part in Part::BOTH {
+            row.push(if !parts.contains(&part) {
+                String::new()
+            } else {
+                match day.solve_within(&input, part, &[], timeout) {
+                    Ok(answer) => answer.to_string(),
+                    Err(RunError::Io(..)) => "missing input".to_owned(),
+                    Err(RunError::Parse(e)) => {
+                        format!("invalid input at {}:{}", e.line(), e.column())
+                    }
+                    Err(RunError::Param(e)) => e,
+                    Err(RunError::Timeout(_)) => "TIMEOUT".to_owned(),
+                    Err(RunError::Panic(_)) => "PANIC".to_owned(),
+                }
+            });
+        }
+        rows.push(row);
+    }
+    table::print(&["Day", "Part 1", "Part 2"], &rows);
+}
@@ -0,0 +1,197 @@
+use std::{fs, path::Path};
+
+use aoc_2024::input_path;
+
+use crate::{args::Args, day_number, Error};
+
+const PUZZLES: &str = "src/puzzles/mod.rs";
+
+/// The module of a new day, `{DAY}` standing for its number.
+const TEMPLATE: &str = r#"use std::io::BufRead;
+
+use crate::{parse, random::Rng, Answer, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = {DAY};
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    lines: Vec<String>,
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let lines = parse::lines(lines)
+        .map(|line| Ok(line?.text))
+        .collect::<Result<_, ParseError>>()?;
+    Ok(PuzzleData { lines })
+}
+
+/// `size` lines of random numbers, until the day's format is known.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{}\n", rng.range(0..=99)))
+        .collect()
+}
+
+fn part1(data: PuzzleData) -> u64 {
+    data.lines.len() as u64
+}
+
+fn part2(_data: PuzzleData) -> u64 {
+    0
+}
+"#;
+
+const EXPECTED: &str = "# Answers to the example, as lines like `part1: 42`. Until there are any,
+# the example is only checked to parse.
+";
+
+/// Adds `puzzle_DAY` to the modules and to `ALL` in the text of
+/// `src/puzzles/mod.rs`, keeping both sorted like the others.
+fn register(puzzles: &str, day: u8) -> Result<String, String> {
+    let module = format!("puzzle_{day}");
+    let declaration = format!("pub mod {module};");
+    let entry = format!("    Day::new::<{module}::Puzzle>(),");
+    let mut lines: Vec<String> = puzzles.lines().map(str::to_owned).collect();
+    if lines.contains(&declaration) {
+        Err(format!("Day {day} is already registered"))?
+    }
+    let Some(all) = lines
+        .iter()
+        .position(|l| l.starts_with("pub const ALL: [Day; "))
+    else {
+        Err("No `ALL` array of days")?
+    };
+
+    // `entry` goes before the first day after it
+    let day_of = |l: &str| {
+        let l = l.trim().strip_prefix("Day::new::<puzzle_")?;
+        l.split_once(':')?.0.parse::<u8>().ok()
+    };
+    let end = all
+        + lines[all..]
+            .iter()
+            .position(|l| l == "];")
+            .ok_or("Unclosed `ALL`")?;
+    let at = (all + 1..end)
+        .find(|&i| day_of(&lines[i]).is_some_and(|d| d > day))
+        .unwrap_or(end);
+    lines.insert(at, entry);
+    let count = (all + 1..=end)
+        .filter(|&i| day_of(&lines[i]).is_some())
+        .count();
+    lines[all] = format!("pub const ALL: [Day; {count}] = [");
+
+    // Declarations are sorted by name, like `rustfmt` does
+    let mods: Vec<usize> = (0..all)
+        .filter(|&i| lines[i].starts_with("pub mod puzzle_"))
+        .collect();
+    let Some(&last) = mods.last() else {
+        Err("No `pub mod puzzle_N;` declarations")?
+    };
+    let at = mods
+        .into_iter()
+        .find(|&i| lines[i].trim_end_matches(';') > declaration.trim_end_matches(';'))
+        .unwrap_or(last + 1);
+    lines.insert(at, declaration);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Writes `text` to `path` unless it exists, returning whether it did.
+fn create(path: &Path, text: &str) -> Result<bool, Error> {
+    if path.exists() {
+        return Ok(false);
+    }
+    let write_err = |e| Error::Failed(format!("Error writing `{}`: {e}", path.display()));
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(write_err)?;
+    }
+    fs::write(path, text).map_err(write_err)?;
+    Ok(true)
+}
+
+pub fn new(args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let args = Args::parse(args, &[])?;
+    args.only(&[])?;
+    let day = day_number(args.positional())?;
+    if !(1..=25).contains(&day) {
+        Err(format!("DAY must be from 1 to 25, got {day}"))?
+    }
+    let Ok(puzzles) = fs::read_to_string(PUZZLES) else {
+        Err(Error::Failed(format!(
+            "No `{PUZZLES}`: run `aoc new` from the repository's root"
+        )))?
+    };
+    let module = Path::new(PUZZLES).with_file_name(format!("puzzle_{day}.rs"));
+    if module.exists() {
+        Err(Error::Failed(format!(
+            "Day {day} already exists in `{}`",
+            module.display()
+        )))?
+    }
+    let puzzles = register(&puzzles, day).map_err(|e| Error::Failed(format!("{PUZZLES}: {e}")))?;
+
+    let example = |ext| input_path(day).with_file_name(format!("puzzle-{day}-example.{ext}"));
+    let files = [
+        (module.clone(), TEMPLATE.replace("{DAY}", &day.to_string())),
+        (example("txt"), String::new()),
+        (example("expected"), EXPECTED.to_owned()),
+        // Left empty for `aoc fetch`
+        (input_path(day), String::new()),
+    ];
+    for (path, text) in &files {
+        match create(path, text)? {
+            true => println!("Created `{}`", path.display()),
+            false => println!("Kept the existing `{}`", path.display()),
+        }
+    }
+    fs::write(PUZZLES, puzzles)
+        .map_err(|e| Error::Failed(format!("Error writing `{PUZZLES}`: {e}")))?;
+    println!("Registered day {day} in `{PUZZLES}`");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let puzzles = "use crate::Day;\n\npub mod puzzle_1;\npub mod puzzle_3;\n\n\
+            pub const ALL: [Day; 2] = [\n    Day::new::<puzzle_1::Puzzle>(),\n    \
+            Day::new::<puzzle_3::Puzzle>(),\n];\n";
+        let added = register(puzzles, 2).unwrap();
+        assert_eq!(
+            added,
+            "use crate::Day;\n\npub mod puzzle_1;\npub mod puzzle_2;\npub mod puzzle_3;\n\n\
+            pub const ALL: [Day; 3] = [\n    Day::new::<puzzle_1::Puzzle>(),\n    \
+            Day::new::<puzzle_2::Puzzle>(),\n    Day::new::<puzzle_3::Puzzle>(),\n];\n"
+        );
+        let added = register(&added, 25).unwrap();
+        assert!(added.contains("pub mod puzzle_25;\npub mod puzzle_3;"));
+        assert!(added.contains("[Day; 4]"));
+        assert!(added.contains("puzzle_3::Puzzle>(),\n    Day::new::<puzzle_25::Puzzle>(),\n];"));
+        assert_eq!(
+            register(&added, 3).unwrap_err(),
+            "Day 3 is already registered"
+        );
+    }
+}
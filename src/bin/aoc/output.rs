@@ -0,0 +1,134 @@
+use std::{io::BufRead, path::Path, str::FromStr, time::Duration};
+
+use aoc_2024::{open_input, Answer, Part, RunError};
+
+use crate::json::Value;
+
+/// How `aoc run` prints its answers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// One JSON object per line.
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "FORMAT must be one of `text`, `json` or `csv`, got `{s}`"
+            )),
+        }
+    }
+}
+
+/// An answer with what it was computed from.
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub input: String,
+    pub hash: String,
+    pub elapsed: Duration,
+}
+
+impl Record {
+    const HEADER: [&'static str; 7] = [
+        "day",
+        "part",
+        "answer",
+        "type",
+        "input",
+        "hash",
+        "elapsed_ns",
+    ];
+
+    /// Fields in the order of [`Record::HEADER`].
+    fn values(&self) -> [String; 7] {
+        [
+            self.day.to_string(),
+            self.part.to_string(),
+            self.answer.to_string(),
+            self.answer.kind().to_owned(),
+            self.input.clone(),
+            self.hash.clone(),
+            self.elapsed.as_nanos().to_string(),
+        ]
+    }
+
+    fn to_json(&self) -> Value {
+        let part: u64 = match self.part {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+        Value::Object(vec![
+            ("day".to_owned(), Value::from(u64::from(self.day))),
+            ("part".to_owned(), Value::from(part)),
+            (
+                "answer".to_owned(),
+                Value::from(self.answer.to_string().as_str()),
+            ),
+            ("type".to_owned(), Value::from(self.answer.kind())),
+            ("input".to_owned(), Value::from(self.input.as_str())),
+            ("hash".to_owned(), Value::from(self.hash.as_str())),
+            (
+                "elapsed_ns".to_owned(),
+                Value::from(self.elapsed.as_nanos() as u64),
+            ),
+        ])
+    }
+}
+
+/// Prints `records` in `format`, which must not be [`Format::Text`]: text
+/// output differs between commands.
+pub fn print(format: Format, records: &[Record]) {
+    match format {
+        Format::Text => unreachable!("text output is up to each command"),
+        Format::Json => {
+            for record in records {
+                println!("{}", record.to_json());
+            }
+        }
+        Format::Csv => {
+            println!("{}", Record::HEADER.join(","));
+            for record in records {
+                let fields: Vec<_> = record.values().iter().map(|v| csv_field(v)).collect();
+                println!("{}", fields.join(","));
+            }
+        }
+    }
+}
+
+pub fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// FNV-1a hash of the contents of `input`, to tell inputs apart.
+pub fn hash(input: &Path) -> Result<String, RunError> {
+    let mut reader = open_input(input)?;
+    let mut hash: u64 = 0xcbf29ce484222325;
+    loop {
+        let buf = reader
+            .fill_buf()
+            .map_err(|e| RunError::Io(input.to_owned(), e))?;
+        if buf.is_empty() {
+            break;
+        }
+        for b in buf {
+            hash = (hash ^ u64::from(*b)).wrapping_mul(0x100000001b3);
+        }
+        let n = buf.len();
+        reader.consume(n);
+    }
+    Ok(format!("{hash:016x}"))
+}
@@ -0,0 +1,479 @@
+//! Client for the puzzle site: `fetch` downloads inputs and `submit` sends
+//! answers.
+//!
+//! `http://` base URLs, like a local mock server, are spoken to directly, and
+//! `https://` ones, like the real site, through `curl`.
+
+use std::{
+    fs,
+    io::{Read, Write},
+    net::TcpStream,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    slice, thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_2024::{input_path, Part};
+
+use crate::{args::Args, day_arg, day_number, params_arg, threads_arg, toml, Error};
+
+const DEFAULT_CONFIG: &str = ".aoc.toml";
+/// When the last request was sent, to pace requests across runs.
+const LAST_REQUEST: &str = "target/aoc-site-last";
+
+/// Settings from the config file, a flat table of `key = value` lines.
+#[derive(Clone, Debug)]
+struct Config {
+    /// The `session` cookie of a logged-in browser.
+    session: String,
+    base_url: String,
+    year: u16,
+    /// The least time between two requests.
+    interval: Duration,
+    user_agent: String,
+}
+
+impl Config {
+    fn load(path: &Path) -> Result<Self, Error> {
+        let text = fs::read_to_string(path).map_err(|e| {
+            Error::Failed(format!(
+                "Error reading config `{}`: {e}; it needs at least `session = \"...\"`",
+                path.display()
+            ))
+        })?;
+        let invalid =
+            |e: String| Error::Failed(format!("Invalid config `{}`: {e}", path.display()));
+        let mut config = Config {
+            session: String::new(),
+            base_url: "https://adventofcode.com".to_owned(),
+            year: 2024,
+            interval: Duration::from_secs(5),
+            user_agent: "aoc-2024 command line".to_owned(),
+        };
+        for entry in toml::parse(&text).map_err(invalid)? {
+            let line = entry.line;
+            let err = |e: &str| invalid(format!("line {line}: {e}"));
+            if !entry.table.is_empty() {
+                Err(err("expected no tables"))?
+            }
+            match entry.key.as_str() {
+                "session" => config.session = entry.value,
+                "base_url" => config.base_url = entry.value.trim_end_matches('/').to_owned(),
+                "year" => config.year = entry.value.parse().map_err(|_| err("expected a year"))?,
+                "interval" => {
+                    let seconds = entry.value.parse().map_err(|_| err("expected seconds"))?;
+                    config.interval = Duration::from_secs(seconds);
+                }
+                "user_agent" => config.user_agent = entry.value,
+                key => Err(err(&format!("unknown key `{key}`")))?,
+            }
+        }
+        if config.session.is_empty() {
+            Err(invalid("missing `session`".to_owned()))?
+        }
+        Ok(config)
+    }
+}
+
+/// What the site made of a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Verdict {
+    Right,
+    /// With the site's hint, like `too high`.
+    Wrong(Option<String>),
+    /// With the time left to wait, like `42s`.
+    TooSoon(Option<String>),
+    /// The part was already solved.
+    Solved,
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+struct Client {
+    config: Config,
+    last_request: PathBuf,
+}
+
+impl Client {
+    fn from_args(args: &Args) -> Result<Self, Error> {
+        let config = Config::load(Path::new(args.value("config").unwrap_or(DEFAULT_CONFIG)))?;
+        Ok(Client {
+            config,
+            last_request: PathBuf::from(LAST_REQUEST),
+        })
+    }
+
+    /// The input of `day`, for the user of the session.
+    fn input(&self, day: u8) -> Result<String, String> {
+        let path = format!("/{}/day/{day}/input", self.config.year);
+        let res = self.request("GET", &path, None)?;
+        match res.status {
+            200 => Ok(res.body),
+            404 => Err(format!("Day {day} is not unlocked yet")),
+            400 | 500 => Err(format!("The site refused the session: {}", res.body.trim())),
+            status => Err(format!("Unexpected status {status}: {}", res.body.trim())),
+        }
+    }
+
+    fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, String> {
+        let path = format!("/{}/day/{day}/answer", self.config.year);
+        let form = format!("level={part}&answer={}", url_encode(answer));
+        let res = self.request("POST", &path, Some(&form))?;
+        match res.status {
+            200 => verdict(&res.body),
+            status => Err(format!("Unexpected status {status}: {}", res.body.trim())),
+        }
+    }
+
+    fn request(&self, method: &str, path: &str, form: Option<&str>) -> Result<Response, String> {
+        self.pace()?;
+        let mut headers = vec![
+            format!("Cookie: session={}", self.config.session),
+            format!("User-Agent: {}", self.config.user_agent),
+        ];
+        if form.is_some() {
+            headers.push("Content-Type: application/x-www-form-urlencoded".to_owned());
+        }
+        let url = format!("{}{path}", self.config.base_url);
+        let raw = match url.strip_prefix("http://") {
+            Some(url) => http(url, method, &headers, form)?,
+            None if url.starts_with("https://") => curl(&url, method, &headers, form)?,
+            None => Err(format!("Unsupported base URL `{}`", self.config.base_url))?,
+        };
+        parse_response(&raw)
+    }
+
+    /// Waits out the interval since the last request of any run.
+    fn pace(&self) -> Result<(), String> {
+        let since_epoch = || {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+        };
+        let last = fs::read_to_string(&self.last_request)
+            .ok()
+            .and_then(|t| t.trim().parse().ok())
+            .map(Duration::from_nanos);
+        if let Some(wait) = last.and_then(|l| (l + self.config.interval).checked_sub(since_epoch()))
+        {
+            eprintln!("Waiting {:.1}s between requests", wait.as_secs_f64());
+            thread::sleep(wait);
+        }
+        let write_err = |e| format!("Error writing `{}`: {e}", self.last_request.display());
+        if let Some(dir) = self
+            .last_request
+            .parent()
+            .filter(|d| !d.as_os_str().is_empty())
+        {
+            fs::create_dir_all(dir).map_err(write_err)?;
+        }
+        // Nanoseconds, as milliseconds rounded down would wait a bit short
+        fs::write(&self.last_request, since_epoch().as_nanos().to_string()).map_err(write_err)
+    }
+}
+
+/// One HTTP/1.0 exchange with `host[:port]/path`, the response ending with
+/// the connection.
+fn http(
+    url: &str,
+    method: &str,
+    headers: &[String],
+    form: Option<&str>,
+) -> Result<Vec<u8>, String> {
+    let (host, path) = url.split_once('/').unwrap_or((url, ""));
+    let addr = match host.contains(':') {
+        true => host.to_owned(),
+        false => format!("{host}:80"),
+    };
+    let io_err = |e| format!("Error talking to `{addr}`: {e}");
+    let mut stream = TcpStream::connect(&addr).map_err(io_err)?;
+    stream
+        .set_read_timeout(Some(Duration::from_secs(30)))
+        .map_err(io_err)?;
+    let mut request = format!("{method} /{path} HTTP/1.0\r\nHost: {host}\r\n");
+    for header in headers {
+        request += &format!("{header}\r\n");
+    }
+    let body = form.unwrap_or_default();
+    if form.is_some() {
+        request += &format!("Content-Length: {}\r\n", body.len());
+    }
+    request += &format!("\r\n{body}");
+    stream.write_all(request.as_bytes()).map_err(io_err)?;
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).map_err(io_err)?;
+    Ok(response)
+}
+
+/// The same exchange through `curl`, the headers going through stdin to keep
+/// the session out of the process list.
+fn curl(
+    url: &str,
+    method: &str,
+    headers: &[String],
+    form: Option<&str>,
+) -> Result<Vec<u8>, String> {
+    let mut cmd = Command::new("curl");
+    cmd.args(["--silent", "--show-error", "--include", "--request", method]);
+    cmd.args(["--header", "@-"]);
+    if let Some(form) = form {
+        cmd.args(["--data-raw", form]);
+    }
+    let curl_err = |e| format!("Error running `curl`, needed for `https`: {e}");
+    let mut child = cmd
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(curl_err)?;
+    let mut stdin = child.stdin.take().expect("piped stdin");
+    stdin
+        .write_all(headers.join("\n").as_bytes())
+        .map_err(curl_err)?;
+    drop(stdin);
+    let output = child.wait_with_output().map_err(curl_err)?;
+    if !output.status.success() {
+        Err(format!(
+            "`curl` failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))?
+    }
+    Ok(output.stdout)
+}
+
+fn parse_response(raw: &[u8]) -> Result<Response, String> {
+    let text = String::from_utf8_lossy(raw);
+    let Some((head, body)) = text.split_once("\r\n\r\n") else {
+        Err("Truncated response")?
+    };
+    let status_line = head.lines().next().unwrap_or_default();
+    let Some(status) = status_line.split(' ').nth(1).and_then(|s| s.parse().ok()) else {
+        Err(format!("Invalid status line `{status_line}`"))?
+    };
+    Ok(Response {
+        status,
+        body: body.to_owned(),
+    })
+}
+
+/// Form encoding, escaping all but letters and digits.
+fn url_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b.is_ascii_alphanumeric() {
+            true => char::from(b).to_string(),
+            false => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Reads the verdict from the text of the answer page's `<article>`.
+fn verdict(page: &str) -> Result<Verdict, String> {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, a)| a.split_once("</article>"))
+        .map_or(page, |(a, _)| a);
+    let text = strip_tags(article);
+    let between = |start: &str, end: &str| {
+        let (_, rest) = text.split_once(start)?;
+        Some(rest.split_once(end)?.0.to_owned())
+    };
+    if text.contains("That's the right answer") {
+        Ok(Verdict::Right)
+    } else if text.contains("That's not the right answer") {
+        let hint = ["too high", "too low"].iter().find(|h| text.contains(*h));
+        Ok(Verdict::Wrong(hint.map(|h| h.to_string())))
+    } else if text.contains("You gave an answer too recently") {
+        Ok(Verdict::TooSoon(between("You have ", " left to wait")))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::Solved)
+    } else {
+        Err(format!("Unexpected answer page: {text}"))
+    }
+}
+
+/// The text of some HTML, its whitespace collapsed.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub fn fetch(args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let args = Args::parse(args, &["force"])?;
+    args.only(&["force", "config"])?;
+    let day = day_number(args.positional())?;
+    if !(1..=25).contains(&day) {
+        Err(format!("DAY must be from 1 to 25, got {day}"))?
+    }
+    let path = input_path(day);
+    // `aoc new` leaves an empty file to fill
+    let fetched = fs::metadata(&path).is_ok_and(|m| m.len() > 0);
+    if fetched && !args.flag("force") {
+        println!(
+            "Input already in `{}`, `--force` fetches it again",
+            path.display()
+        );
+        return Ok(());
+    }
+    let input = Client::from_args(&args)?
+        .input(day)
+        .map_err(Error::Failed)?;
+    let write_err = |e| Error::Failed(format!("Error writing `{}`: {e}", path.display()));
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(write_err)?;
+    }
+    fs::write(&path, input).map_err(write_err)?;
+    println!("Saved the input of day {day} to `{}`", path.display());
+    Ok(())
+}
+
+pub fn submit(args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let args = Args::parse(args, &[])?;
+    args.only(&["input", "param", "config", "threads"])?;
+    let [day, part, given @ ..] = args.positional() else {
+        Err("Expected DAY and PART")?
+    };
+    let part: Part = part.parse()?;
+    let client = Client::from_args(&args)?;
+    let (day, answer) = match given {
+        [answer] => {
+            if args.value("input").is_some() || args.value("param").is_some() {
+                Err("`--input` and `--param` only go without an ANSWER")?
+            }
+            (day_number(slice::from_ref(day))?, answer.clone())
+        }
+        [] => {
+            threads_arg(&args)?;
+            let day = day_arg(slice::from_ref(day))?;
+            let input = args
+                .value("input")
+                .map_or_else(|| day.default_input(), PathBuf::from);
+            let answer = day.solve(&input, part, &params_arg(&args)?)?;
+            println!("Got result `{answer}` for part {part}!");
+            (day.day, answer.to_string())
+        }
+        _ => Err("Expected at most one ANSWER")?,
+    };
+    match client.submit(day, part, &answer).map_err(Error::Failed)? {
+        Verdict::Right => println!("That's the right answer for part {part} of day {day}!"),
+        Verdict::Solved => println!("Part {part} of day {day} is already solved"),
+        Verdict::Wrong(hint) => Err(Error::Failed(match hint {
+            Some(hint) => format!("`{answer}` is not the right answer: {hint}"),
+            None => format!("`{answer}` is not the right answer"),
+        }))?,
+        Verdict::TooSoon(wait) => Err(Error::Failed(match wait {
+            Some(wait) => format!("Answered too recently, {wait} left to wait"),
+            None => "Answered too recently".to_owned(),
+        }))?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        io::{BufRead, BufReader},
+        net::TcpListener,
+        process,
+        time::Instant,
+    };
+
+    use super::*;
+
+    /// Answers each connection with the next of `responses`, returning the
+    /// requests received.
+    fn mock_server(responses: Vec<&'static str>) -> (u16, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for response in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let (mut request, mut length) = (String::new(), 0);
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(l) = line.strip_prefix("Content-Length: ") {
+                        length = l.trim().parse().unwrap();
+                    }
+                    request += &line;
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                requests.push(request + &String::from_utf8(body).unwrap());
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (port, server)
+    }
+
+    #[test]
+    fn test_client() {
+        let (port, server) = mock_server(vec![
+            "HTTP/1.0 200 OK\r\n\r\n1 2\n",
+            "HTTP/1.0 404 Not Found\r\n\r\n404 Not Found",
+            "HTTP/1.0 200 OK\r\n\r\n<main><article><p>That's the right answer!</p></article>",
+        ]);
+        let client = Client {
+            config: Config {
+                session: "abc".to_owned(),
+                base_url: format!("http://127.0.0.1:{port}/site"),
+                year: 2024,
+                interval: Duration::from_millis(100),
+                user_agent: "test".to_owned(),
+            },
+            last_request: env::temp_dir().join(format!("aoc-site-test-{}", process::id())),
+        };
+        let start = Instant::now();
+        assert_eq!(client.input(5).unwrap(), "1 2\n");
+        assert_eq!(client.input(30).unwrap_err(), "Day 30 is not unlocked yet");
+        assert_eq!(client.submit(5, Part::Two, "1,2"), Ok(Verdict::Right));
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        fs::remove_file(&client.last_request).unwrap();
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /site/2024/day/5/input HTTP/1.0\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc\r\n"));
+        assert!(requests[2].starts_with("POST /site/2024/day/5/answer "));
+        assert!(requests[2].ends_with("\r\n\r\nlevel=2&answer=1%2C2"));
+
+        let page = |text| format!("<html><article><p>{text}</p></article></html>");
+        assert_eq!(
+            verdict(&page(
+                "That's not the right answer; your answer is <em>too low</em>."
+            )),
+            Ok(Verdict::Wrong(Some("too low".to_owned())))
+        );
+        assert_eq!(
+            verdict(&page(
+                "You gave an answer too recently. You have 34s left to wait."
+            )),
+            Ok(Verdict::TooSoon(Some("34s".to_owned())))
+        );
+        assert_eq!(
+            verdict(&page("You don't seem to be solving the right level.")),
+            Ok(Verdict::Solved)
+        );
+        assert!(verdict(&page("Something else")).is_err());
+    }
+}
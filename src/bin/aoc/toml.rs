@@ -0,0 +1,129 @@
+/// One `key = value` line of a TOML file, with the table it belongs to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub line: usize,
+    pub table: Vec<String>,
+    pub key: String,
+    /// Strings unquoted and unescaped, integers as written.
+    pub value: String,
+}
+
+/// Just enough TOML for flat tables of strings and integers, like
+/// `answers.toml`: no arrays, inline tables or multi-line strings.
+pub fn parse(text: &str) -> Result<Vec<Entry>, String> {
+    let mut entries = Vec::new();
+    let mut table = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let err = |e: String| format!("line {line_no}: {e}");
+        let mut rest = line.trim_start();
+        if rest.is_empty() || rest.starts_with('#') {
+            continue;
+        }
+        if let Some(header) = rest.strip_prefix('[') {
+            let (keys, after) = parse_keys(header).map_err(err)?;
+            rest = after
+                .strip_prefix(']')
+                .ok_or_else(|| err("Expected `]`".to_owned()))?;
+            table = keys;
+        } else {
+            let (mut keys, after) = parse_keys(rest).map_err(err)?;
+            let after = after
+                .strip_prefix('=')
+                .ok_or_else(|| err("Expected `=`".to_owned()))?;
+            let (value, after) = parse_value(after.trim_start()).map_err(err)?;
+            let key = keys.pop().expect("at least one key");
+            entries.push(Entry {
+                line: line_no,
+                table: table.iter().cloned().chain(keys).collect(),
+                key,
+                value,
+            });
+            rest = after;
+        }
+        let rest = rest.trim_start();
+        if !(rest.is_empty() || rest.starts_with('#')) {
+            Err(err(format!("Unexpected `{rest}`")))?
+        }
+    }
+    Ok(entries)
+}
+
+/// Dotted keys, bare or quoted, up to what follows them.
+fn parse_keys(text: &str) -> Result<(Vec<String>, &str), String> {
+    let mut keys = Vec::new();
+    let mut rest = text;
+    loop {
+        rest = rest.trim_start();
+        let (key, after) = if rest.starts_with('"') {
+            parse_string(rest)?
+        } else {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+                .unwrap_or(rest.len());
+            if end == 0 {
+                Err("Expected a key")?
+            }
+            (rest[..end].to_owned(), &rest[end..])
+        };
+        keys.push(key);
+        rest = after.trim_start();
+        match rest.strip_prefix('.') {
+            Some(after) => rest = after,
+            None => return Ok((keys, rest)),
+        }
+    }
+}
+
+fn parse_value(text: &str) -> Result<(String, &str), String> {
+    if text.starts_with('"') {
+        return parse_string(text);
+    }
+    let end = text
+        .find(|c: char| !(c.is_ascii_digit() || c == '-' || c == '+'))
+        .unwrap_or(text.len());
+    let number = &text[..end];
+    if number.parse::<i128>().is_err() {
+        Err("Expected a string or an integer")?
+    }
+    Ok((number.to_owned(), &text[end..]))
+}
+
+/// A basic `"..."` string at the start of `text`, and what follows it.
+fn parse_string(text: &str) -> Result<(String, &str), String> {
+    let mut s = String::new();
+    let mut chars = text.char_indices().skip(1);
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((s, &text[i + 1..])),
+            '\\' => match chars.next().map(|(_, c)| c) {
+                Some('n') => s.push('\n'),
+                Some('t') => s.push('\t'),
+                Some(c @ ('"' | '\\')) => s.push(c),
+                _ => Err("Unsupported escape in string")?,
+            },
+            c => s.push(c),
+        }
+    }
+    Err("Unterminated string".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let text = "# answers\n[day1.\"data/a b.txt\"]\npart1 = \"1,2\" # ok\npart2 = 42\n";
+        let entries = parse(text).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].table, ["day1", "data/a b.txt"]);
+        assert_eq!(
+            (entries[0].key.as_str(), entries[0].value.as_str()),
+            ("part1", "1,2")
+        );
+        assert_eq!((entries[1].line, entries[1].value.as_str()), (4, "42"));
+        assert_eq!(parse("\n[a]\nb c").unwrap_err(), "line 3: Expected `=`");
+        assert!(parse("a = \"b").is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use std::{fs, path::PathBuf};
+
+use aoc_2024::{puzzles, Day, Part, RunError};
+
+use crate::{args::Args, day_arg, parts_arg, table, threads_arg, timeout_arg, toml, Error};
+
+pub const DEFAULT_ANSWERS: &str = "answers.toml";
+
+/// A recorded answer, from a `[dayN."input"]` table's `partN` key.
+pub struct Expected {
+    pub day: u8,
+    pub input: PathBuf,
+    pub part: Part,
+    pub answer: String,
+}
+
+pub fn load_answers(path: &str) -> Result<Vec<Expected>, Error> {
+    let text = fs::read_to_string(path).map_err(|e| RunError::Io(path.into(), e))?;
+    let invalid = |line, e: &str| Error::Failed(format!("Invalid answers `{path}`:{line}: {e}"));
+    toml::parse(&text)
+        .map_err(|e| Error::Failed(format!("Invalid answers `{path}`: {e}")))?
+        .into_iter()
+        .map(|entry| {
+            let [day, input] = entry.table.as_slice() else {
+                Err(invalid(entry.line, "expected a `[dayN.\"FILE\"]` table"))?
+            };
+            let day = day
+                .strip_prefix("day")
+                .and_then(|d| d.parse().ok())
+                .ok_or_else(|| invalid(entry.line, "expected a `[dayN.\"FILE\"]` table"))?;
+            let part = entry
+                .key
+                .strip_prefix("part")
+                .and_then(|p| p.parse().ok())
+                .ok_or_else(|| invalid(entry.line, "expected `part1` or `part2`"))?;
+            Ok(Expected {
+                day,
+                input: PathBuf::from(input),
+                part,
+                answer: entry.value,
+            })
+        })
+        .collect()
+}
+
+pub fn verify(args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let args = Args::parse(args, &[])?;
+    args.only(&["part", "answers", "threads", "timeout"])?;
+    let parts = parts_arg(&args)?;
+    threads_arg(&args)?;
+    let timeout = timeout_arg(&args)?;
+    let days: Vec<&Day> = if args.positional().is_empty() {
+        puzzles::ALL.iter().collect()
+    } else {
+        vec![day_arg(args.positional())?]
+    };
+    let answers = load_answers(args.value("answers").unwrap_or(DEFAULT_ANSWERS))?;
+    if let Some(unknown) = answers.iter().find(|a| puzzles::get(a.day).is_none()) {
+        Err(Error::Failed(format!(
+            "Answers recorded for day {}, which has no solution",
+            unknown.day
+        )))?
+    }
+
+    let mut rows = Vec::new();
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in days {
+        for &part in &parts {
+            let expected: Vec<&Expected> = answers
+                .iter()
+                .filter(|a| a.day == day.day && a.part == part)
+                .collect();
+            // The real input should always have an answer, other inputs may
+            if !expected.iter().any(|a| a.input == day.default_input()) {
+                missing += 1;
+                rows.push(vec![
+                    day.day.to_string(),
+                    part.to_string(),
+                    day.default_input().display().to_string(),
+                    "missing".to_owned(),
+                ]);
+            }
+            for a in expected {
+                let status = match day.solve_within(&a.input, part, &[], timeout) {
+                    Ok(got) if got.to_string() == a.answer => {
+                        passed += 1;
+                        "pass".to_owned()
+                    }
+                    Ok(got) => {
+                        failed += 1;
+                        format!("FAIL: got `{got}`, expected `{}`", a.answer)
+                    }
+                    Err(e) => {
+                        failed += 1;
+                        let prefix = match e {
+                            RunError::Timeout(_) | RunError::Panic(_) => "",
+                            _ => "FAIL: ",
+                        };
+                        // Parse errors go on with the line they point in, too
+                        // wide for the table
+                        let e = e.to_string();
+                        format!("{prefix}{}", e.lines().next().unwrap_or_default())
+                    }
+                };
+                rows.push(vec![
+                    day.day.to_string(),
+                    part.to_string(),
+                    a.input.display().to_string(),
+                    status,
+                ]);
+            }
+        }
+    }
+    table::print(&["Day", "Part", "Input", "Result"], &rows);
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 || missing > 0 {
+        Err(Error::Failed(format!(
+            "{failed} answer(s) did not match the recorded ones, {missing} missing"
+        )))?
+    }
+    Ok(())
+}
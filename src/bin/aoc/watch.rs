@@ -0,0 +1,329 @@
+//! `aoc watch`: rebuilds and re-runs a day whenever its source or inputs
+//! change, found by polling their modification times.
+
+use std::{
+    env, fs,
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use aoc_2024::{expected::ExpectedFile, input_path, Part};
+
+use crate::{
+    args::Args,
+    bench::format_duration,
+    child, day_number,
+    json::Value,
+    table,
+    verify::{self, DEFAULT_ANSWERS},
+    Error,
+};
+
+/// The files of a day, with when each was last modified.
+type Stamps = Vec<(PathBuf, Option<SystemTime>)>;
+
+/// The answers and parameters listed by an example's `.expected` file.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Expected {
+    answers: Vec<(Part, String)>,
+    pub params: Vec<String>,
+}
+
+impl Expected {
+    /// Missing files expect nothing, like examples only checked to parse.
+    fn load(path: &Path) -> Result<Self, String> {
+        let Ok(text) = fs::read_to_string(path) else {
+            return Ok(Expected::default());
+        };
+        let file = ExpectedFile::parse(&text).map_err(|e| format!("`{}` {e}", path.display()))?;
+        Ok(Expected {
+            answers: file
+                .answers
+                .into_iter()
+                .map(|(part, answer)| (Part::BOTH[usize::from(part) - 1], answer))
+                .collect(),
+            params: file
+                .params
+                .into_iter()
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect(),
+        })
+    }
+
+    /// What `input` expects, from its `.expected` file and the answers
+    /// `recorded` in `answers.toml`.
+    pub fn for_input(day: u8, input: &Path, recorded: &[verify::Expected]) -> Result<Self, String> {
+        let mut expected = Expected::load(&input.with_extension("expected"))?;
+        let recorded = recorded.iter().filter(|a| a.day == day && a.input == input);
+        expected
+            .answers
+            .extend(recorded.map(|a| (a.part, a.answer.clone())));
+        Ok(expected)
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        let answer = self.answers.iter().find(|(p, _)| *p == part);
+        answer.map(|(_, a)| a.as_str())
+    }
+}
+
+fn source(day: u8) -> PathBuf {
+    PathBuf::from(format!("src/puzzles/puzzle_{day}.rs"))
+}
+
+/// The day's examples, `data/puzzle-DAY-SUFFIX.txt`, then its real input.
+fn inputs(day: u8) -> Vec<PathBuf> {
+    let real = input_path(day);
+    let prefix = format!("puzzle-{day}-");
+    let mut inputs: Vec<PathBuf> = fs::read_dir(real.parent().expect("a `data` directory"))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            (name.starts_with(&prefix) && name.ends_with(".txt")).then_some(path)
+        })
+        .collect();
+    inputs.sort();
+    if real.exists() {
+        inputs.push(real);
+    }
+    inputs
+}
+
+fn stamps(day: u8) -> Stamps {
+    let expected = inputs(day)
+        .into_iter()
+        .flat_map(|input| [input.with_extension("expected"), input]);
+    [source(day)]
+        .into_iter()
+        .chain(expected)
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Rebuilds the running binary, in the profile it was built in, returning
+/// where cargo put it, or `None` if the build failed. That path is run rather
+/// than the running copy, which the build replaces.
+fn rebuild() -> Result<Option<PathBuf>, Error> {
+    let mut cargo = Command::new(env::var_os("CARGO").unwrap_or("cargo".into()));
+    cargo.args([
+        "build",
+        "--quiet",
+        "--bin",
+        "aoc",
+        "--message-format=json-render-diagnostics",
+    ]);
+    let exe = env::current_exe().unwrap_or_default();
+    if exe.parent().and_then(Path::file_name) == Some("release".as_ref()) {
+        cargo.arg("--release");
+    }
+    let output = cargo
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| Error::Failed(format!("Error running cargo: {e}")))?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+    let exe = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|l| Value::parse(l).ok())
+        .filter(|m| m.get("reason").and_then(Value::as_str) == Some("compiler-artifact"))
+        .filter(|m| {
+            let target = m.get("target").and_then(|t| t.get("name"));
+            target.and_then(Value::as_str) == Some("aoc")
+        })
+        .find_map(|m| Some(PathBuf::from(m.get("executable")?.as_str()?)));
+    match exe {
+        Some(exe) => Ok(Some(exe)),
+        None => Err(Error::Failed("cargo built no `aoc` binary".to_owned())),
+    }
+}
+
+const HEADER: [&str; 6] = ["Input", "Part", "Answer", "Expected", "Time", "Result"];
+
+/// How a run of the day ended, unless the files changed first.
+enum Outcome {
+    Done(Vec<Vec<String>>),
+    Changed,
+}
+
+/// Shows the rows so far, redrawn as a table on a terminal, else by printing
+/// the last one.
+fn show(day: u8, rows: &[Vec<String>]) {
+    if io::stdout().is_terminal() {
+        print!("\x1b[H\x1b[2J");
+        println!("Running day {day}…");
+        table::print(&HEADER, rows);
+        return;
+    }
+    if rows.len() == 1 {
+        println!("{}", HEADER.join(" | "));
+    }
+    if let Some(row) = rows.last() {
+        println!("{}", row.join(" | "));
+    }
+}
+
+/// Runs each part of the day on each input, showing its row once done. Parts
+/// are stopped after `timeout`, and the run given up on a change to the files,
+/// so a runaway solver does not block the next try.
+fn refresh(
+    day: u8,
+    exe: &Path,
+    poll: Duration,
+    timeout: Duration,
+    stamps: &Stamps,
+) -> Result<Outcome, Error> {
+    let recorded = verify::load_answers(DEFAULT_ANSWERS).unwrap_or_default();
+    let mut rows = Vec::new();
+    for input in inputs(day) {
+        let name = input.display().to_string();
+        let expected = match Expected::for_input(day, &input, &recorded) {
+            Ok(expected) => expected,
+            Err(e) => {
+                rows.push(vec![name, String::new(), e]);
+                show(day, &rows);
+                continue;
+            }
+        };
+        for part in Part::BOTH {
+            // The budget is kept here, as `--timeout` may be over the child's
+            let mut child =
+                child::spawn(exe, day, &input, Some(part), &expected.params, None, None)
+                    .map_err(|e| Error::Failed(format!("Error running `aoc`: {e}")))?;
+            let start = Instant::now();
+            let (mut checked, mut changed) = (start, false);
+            // Quick parts are seen to end at once, the files looked at less often
+            let status = child::wait(&mut child, child::POLL, || {
+                if checked.elapsed() >= poll {
+                    checked = Instant::now();
+                    changed = *stamps != self::stamps(day);
+                }
+                changed || start.elapsed() > timeout
+            });
+            if changed {
+                return Ok(Outcome::Changed);
+            }
+            let wanted = expected.answer(part).unwrap_or_default().to_owned();
+            let (stdout, stderr) = child::output(child);
+            let answer = child::answers(&stdout).into_iter().next();
+            rows.push(match (status, answer) {
+                (None, _) => vec![
+                    name.clone(),
+                    part.to_string(),
+                    String::new(),
+                    wanted,
+                    format!("over {}", format_duration(timeout)),
+                    "TIMEOUT".to_owned(),
+                ],
+                (Some(_), Some(child::Answer { answer, elapsed })) => {
+                    let result = match expected.answer(part) {
+                        Some(a) if a == answer => "pass",
+                        Some(_) => "FAIL",
+                        None => "",
+                    };
+                    vec![
+                        name.clone(),
+                        part.to_string(),
+                        answer,
+                        wanted,
+                        elapsed.map_or(String::new(), format_duration),
+                        result.to_owned(),
+                    ]
+                }
+                (Some(status), None) => {
+                    let error = stderr.lines().next().map(str::to_owned);
+                    let error = error.unwrap_or_else(|| format!("exited with {status}"));
+                    vec![name.clone(), part.to_string(), error]
+                }
+            });
+            show(day, &rows);
+        }
+    }
+    Ok(Outcome::Done(rows))
+}
+
+pub fn watch(args: impl Iterator<Item = String>) -> Result<(), Error> {
+    let args = Args::parse(args, &[])?;
+    args.only(&["poll", "timeout"])?;
+    // The day may be new, the running binary not knowing it yet
+    let day = day_number(args.positional())?;
+    let poll = Duration::from_millis(args.parsed("poll")?.unwrap_or(500));
+    if poll.is_zero() {
+        Err("`--poll` must be positive")?
+    }
+    let timeout = args.parsed::<f64>("timeout")?.unwrap_or(10.0);
+    if timeout.is_nan() || timeout <= 0.0 {
+        Err(format!("`--timeout` must be positive, got `{timeout}`"))?
+    }
+    let timeout = Duration::from_secs_f64(timeout);
+    if !source(day).exists() {
+        Err(Error::Failed(format!(
+            "No `{}`: run `aoc watch` from the repository's root",
+            source(day).display()
+        )))?
+    }
+    let mut seen = Stamps::new();
+    // When the source last built, only its changes needing a rebuild
+    let mut built = None;
+    let mut exe = PathBuf::new();
+    loop {
+        let current = stamps(day);
+        if current == seen {
+            thread::sleep(poll);
+            continue;
+        }
+        seen = current;
+        if io::stdout().is_terminal() {
+            print!("\x1b[H\x1b[2J");
+        }
+        println!("Running day {day}…");
+        let source_modified = seen[0].1;
+        if built != Some(source_modified) {
+            let Some(rebuilt) = rebuild()? else {
+                println!("Build failed, watching for a fix…");
+                continue;
+            };
+            (built, exe) = (Some(source_modified), rebuilt);
+        }
+        let Outcome::Done(rows) = refresh(day, &exe, poll, timeout, &seen)? else {
+            continue;
+        };
+        let failed = rows
+            .iter()
+            .filter(|r| r.get(5).is_none_or(|c| c == "FAIL" || c == "TIMEOUT"));
+        println!("{} failed", failed.count());
+        println!("Watching `{}` and the day's inputs…", source(day).display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected() {
+        let path = env::temp_dir().join(format!("aoc-watch-test-{}", std::process::id()));
+        fs::write(&path, "# comment\npart1: 1,2\nparam: size=11x7\npart2: 3\n").unwrap();
+        let expected = Expected::load(&path);
+        fs::write(&path, "part1: 1\nanswer: 2\n").unwrap();
+        let invalid = Expected::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            expected,
+            Ok(Expected {
+                answers: vec![(Part::One, "1,2".to_owned()), (Part::Two, "3".to_owned())],
+                params: vec!["size=11x7".to_owned()],
+            })
+        );
+        assert!(invalid.unwrap_err().contains("line 2:"));
+        assert_eq!(Expected::load(&path), Ok(Expected::default()));
+    }
+}
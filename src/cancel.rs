@@ -0,0 +1,104 @@
+//! Wall-clock budgets for solvers: a part runs on a worker thread, whose long
+//! loops call [`check`] to stop once the budget is spent.
+
+use std::{
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+/// How long a cancelled solver may take to reach a [`check`].
+const GRACE: Duration = Duration::from_secs(1);
+
+/// Shared flag asking a run to stop.
+#[derive(Clone, Debug, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// The token [`check`] looks at on this thread, for threads the solver
+/// spawns to pass on.
+pub fn current() -> Option<Token> {
+    CURRENT.with(|t| t.borrow().clone())
+}
+
+/// Makes [`check`] look at `token` on this thread.
+pub fn install(token: Option<Token>) {
+    CURRENT.with(|t| *t.borrow_mut() = token);
+}
+
+/// Unwinds out of the solver if its run was cancelled, to be called in loops
+/// that may run for long. Does nothing outside [`within`].
+pub fn check() {
+    if CURRENT.with(|t| t.borrow().as_ref().is_some_and(Token::is_cancelled)) {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+/// The payload [`check`] unwinds with, which the panic hook does not print.
+struct Cancelled;
+
+/// `f()` run on a worker thread, or `None` if it takes over `timeout`: it is
+/// then cancelled, and left behind if it does not check in time. Panics of
+/// `f` are raised again.
+pub fn within<R: Send + 'static>(
+    timeout: Duration,
+    f: impl FnOnce() -> R + Send + 'static,
+) -> Option<R> {
+    let token = Token::default();
+    let (sender, receiver) = mpsc::channel();
+    let worker_token = token.clone();
+    thread::spawn(move || {
+        install(Some(worker_token));
+        _ = sender.send(panic::catch_unwind(AssertUnwindSafe(f)));
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(res)) => Some(res),
+        Ok(Err(payload)) => panic::resume_unwind(payload),
+        Err(_) => {
+            token.cancel();
+            _ = receiver.recv_timeout(GRACE);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parallel;
+
+    #[test]
+    fn test_within() {
+        assert_eq!(within(Duration::from_secs(5), || 42), Some(42));
+        let spin = || loop {
+            check();
+        };
+        assert_eq!(within(Duration::from_millis(20), spin), None);
+        // Threads of the solver stop too
+        let items = [1, 2, 3];
+        let spin_all = move || parallel::map(&items, |_| spin());
+        assert!(within(Duration::from_millis(20), spin_all).is_none());
+        let panicked = panic::catch_unwind(|| within(Duration::from_secs(5), || panic!("no")));
+        assert!(panicked.is_err());
+        // Outside `within`, `check` never stops
+        check();
+    }
+}
@@ -0,0 +1,65 @@
+//! The `.expected` files next to example inputs, listing their answers as
+//! lines like `part1: 42` and the day's parameters as lines like
+//! `param: size=11x7`. `build.rs` includes this module too, so it only uses
+//! `std`.
+
+/// The answers and parameters of an example.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExpectedFile {
+    /// Answers by part, `1` or `2`.
+    pub answers: Vec<(u8, String)>,
+    pub params: Vec<(String, String)>,
+}
+
+impl ExpectedFile {
+    /// Skips blank lines and `#` comments, rejecting any other line.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut expected = ExpectedFile::default();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = |wanted: &str| Err(format!("line {}: {wanted}, got `{line}`", i + 1));
+            match line.split_once(':').map(|(k, v)| (k.trim(), v.trim())) {
+                Some(("param", param)) => {
+                    let Some((name, value)) = param.split_once('=') else {
+                        return invalid("expected `param: NAME=VALUE`");
+                    };
+                    expected.params.push((name.to_owned(), value.to_owned()));
+                }
+                Some(("part1", answer)) => expected.answers.push((1, answer.to_owned())),
+                Some(("part2", answer)) => expected.answers.push((2, answer.to_owned())),
+                _ => {
+                    return invalid(
+                        "expected `part1: ANSWER`, `part2: ANSWER` or `param: NAME=VALUE`",
+                    )
+                }
+            }
+        }
+        Ok(expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let text = "# comment\npart1: 1,2\n\nparam: size=11x7\npart2: 3\n";
+        assert_eq!(
+            ExpectedFile::parse(text),
+            Ok(ExpectedFile {
+                answers: vec![(1, "1,2".to_owned()), (2, "3".to_owned())],
+                params: vec![("size".to_owned(), "11x7".to_owned())],
+            })
+        );
+        assert_eq!(
+            ExpectedFile::parse("part1: 4\npart 2: 5").unwrap_err(),
+            "line 2: expected `part1: ANSWER`, `part2: ANSWER` or `param: NAME=VALUE`, \
+             got `part 2: 5`"
+        );
+        assert!(ExpectedFile::parse("param: size").is_err());
+    }
+}
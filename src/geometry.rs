@@ -0,0 +1,261 @@
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+use crate::Coord;
+
+/// Signed 2D vector, for offsets and positions that may leave a grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2D {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Vec2D {
+    pub const ZERO: Vec2D = Vec2D::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Vec2D { x, y }
+    }
+
+    /// Manhattan length, `|x| + |y|`.
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    pub fn manhattan_to(self, other: Vec2D) -> usize {
+        (other - self).manhattan()
+    }
+
+    /// The grid coordinates of `self`, if neither component is negative.
+    pub fn coord(self) -> Option<Coord> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+}
+
+impl From<Coord> for Vec2D {
+    fn from((x, y): Coord) -> Self {
+        Vec2D::new(x as isize, y as isize)
+    }
+}
+
+impl Add for Vec2D {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vec2D::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Vec2D {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec2D {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec2D::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Vec2D {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vec2D {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Vec2D::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vec2D {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Vec2D::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl MulAssign<isize> for Vec2D {
+    fn mul_assign(&mut self, rhs: isize) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<isize> for Vec2D {
+    type Output = Self;
+
+    fn div(self, rhs: isize) -> Self::Output {
+        Vec2D::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+/// Componentwise Euclidean remainder, wrapping positions into a
+/// `rhs.x` by `rhs.y` area.
+impl Rem for Vec2D {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Vec2D::new(self.x.rem_euclid(rhs.x), self.y.rem_euclid(rhs.y))
+    }
+}
+
+/// One of the four directions on a map, `Up` being towards the first row.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// Clockwise from `Up`.
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// Parses one of `^`, `>`, `v` or `<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Dir::Up),
+            '>' => Some(Dir::Right),
+            'v' => Some(Dir::Down),
+            '<' => Some(Dir::Left),
+            _ => None,
+        }
+    }
+
+    /// The inverse of [`Dir::from_arrow`].
+    pub fn arrow(self) -> char {
+        match self {
+            Dir::Up => '^',
+            Dir::Right => '>',
+            Dir::Down => 'v',
+            Dir::Left => '<',
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Dir::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Dir::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Dir::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn delta(self) -> Vec2D {
+        match self {
+            Dir::Up => Vec2D::new(0, -1),
+            Dir::Right => Vec2D::new(1, 0),
+            Dir::Down => Vec2D::new(0, 1),
+            Dir::Left => Vec2D::new(-1, 0),
+        }
+    }
+
+    /// The coordinates one step from `coord`, unless that leaves the first
+    /// row or column.
+    pub fn step(self, coord: Coord) -> Option<Coord> {
+        (Vec2D::from(coord) + self.delta()).coord()
+    }
+}
+
+/// One of the eight directions on a map, diagonals included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// Clockwise from `Up`.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// Turns by 45 degrees.
+    pub fn turn_right(self) -> Self {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45 degrees.
+    pub fn turn_left(self) -> Self {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn delta(self) -> Vec2D {
+        match self {
+            Dir8::Up => Vec2D::new(0, -1),
+            Dir8::UpRight => Vec2D::new(1, -1),
+            Dir8::Right => Vec2D::new(1, 0),
+            Dir8::DownRight => Vec2D::new(1, 1),
+            Dir8::Down => Vec2D::new(0, 1),
+            Dir8::DownLeft => Vec2D::new(-1, 1),
+            Dir8::Left => Vec2D::new(-1, 0),
+            Dir8::UpLeft => Vec2D::new(-1, -1),
+        }
+    }
+
+    pub fn step(self, coord: Coord) -> Option<Coord> {
+        (Vec2D::from(coord) + self.delta()).coord()
+    }
+}
+
+impl From<Dir> for Dir8 {
+    fn from(dir: Dir) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.reverse().delta(), -dir.delta());
+            assert_eq!(Dir8::from(dir).delta(), dir.delta());
+            assert_eq!(Dir::from_arrow(dir.arrow()), Some(dir));
+        }
+        assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+        assert_eq!(Dir8::UpLeft.reverse(), Dir8::DownRight);
+    }
+
+    #[test]
+    fn test_vec() {
+        let v = Vec2D::new(3, -4);
+        assert_eq!(v.manhattan(), 7);
+        assert_eq!(v.coord(), None);
+        assert_eq!((v * 2 - v) / 1, v);
+        assert_eq!(v % Vec2D::new(2, 3), Vec2D::new(1, 2));
+        assert_eq!(Dir::Left.step((0, 4)), None);
+        assert_eq!(Dir::Down.step((0, 4)), Some((0, 5)));
+    }
+}
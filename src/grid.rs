@@ -0,0 +1,303 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::{Dir, Dir8, Vec2D},
+    parse::Line,
+    ParseError,
+};
+
+/// `(x, y)` position in a [`Grid`], `(0, 0)` being the top left corner.
+pub type Coord = (usize, usize);
+
+/// Rectangular map stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from `cells` given row by row.
+    ///
+    /// Panics if `cells` does not fill whole rows of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of {width}",
+            cells.len()
+        );
+        Grid {
+            height: cells.len() / width,
+            cells,
+            width,
+        }
+    }
+
+    /// Parses one cell per char, `cell` returning `None` for chars that are
+    /// not `expected`. Every row must be as wide as the first one.
+    pub fn parse(
+        rows: &[Line],
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let Some(width) = rows.first().map(|l| l.text.chars().count()) else {
+            Err(ParseError::eof(1, "a map"))?
+        };
+        if width == 0 {
+            Err(rows[0].error_at(0, expected))?
+        }
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for row in rows {
+            let mut len = 0;
+            for (x, c) in row.text.chars().enumerate() {
+                if x == width {
+                    Err(row.error_at(x, format!("end of a row of {width} cells")))?
+                }
+                cells.push(cell(c).ok_or_else(|| row.error_at(x, expected))?);
+                len += 1;
+            }
+            if len < width {
+                Err(row.error_at(len, format!("a row of {width} cells")))?
+            }
+        }
+        Ok(Grid::from_cells(width, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Coord) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn index_of(&self, coord: Coord) -> Option<usize> {
+        self.contains(coord)
+            .then_some(coord.0 + coord.1 * self.width)
+    }
+
+    pub fn coord_of(&self, index: usize) -> Coord {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|i| &mut self.cells[i])
+    }
+
+    pub fn swap(&mut self, a: Coord, b: Coord) {
+        let (Some(i), Some(j)) = (self.index_of(a), self.index_of(b)) else {
+            panic!(
+                "{a:?} or {b:?} is out of a {}x{} grid",
+                self.width, self.height
+            )
+        };
+        self.cells.swap(i, j);
+    }
+
+    /// All cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Every cell with its coordinates, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (self.coord_of(i), c))
+    }
+
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// Coordinates of the first cell matching `pred`, row by row.
+    pub fn find(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Coord> {
+        self.cells
+            .iter()
+            .position(&mut pred)
+            .map(|i| self.coord_of(i))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Checked conversion of `v` to coordinates inside the grid.
+    pub fn coord(&self, v: Vec2D) -> Option<Coord> {
+        v.coord().filter(|c| self.contains(*c))
+    }
+
+    /// The coordinates `delta` away from `coord`, if still inside the grid.
+    pub fn step(&self, coord: Coord, delta: Vec2D) -> Option<Coord> {
+        self.coord(Vec2D::from(coord) + delta)
+    }
+
+    /// In-bounds neighbours sharing an edge, in [`Dir::ALL`] order.
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> {
+        let (width, height) = (self.width, self.height);
+        Dir::ALL.into_iter().filter_map(move |d| {
+            let n = d.step(coord)?;
+            (n.0 < width && n.1 < height).then_some(n)
+        })
+    }
+
+    /// In-bounds neighbours sharing an edge or a corner, in [`Dir8::ALL`]
+    /// order.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> {
+        let (width, height) = (self.width, self.height);
+        Dir8::ALL.into_iter().filter_map(move |d| {
+            let n = d.step(coord)?;
+            (n.0 < width && n.1 < height).then_some(n)
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[(y * self.width)..((y + 1) * self.width)]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// `\` diagonals, each from its top left end, starting at the bottom left
+    /// corner of the grid.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..(self.width + self.height - 1)).map(move |k| {
+            let (x, y) = if k < self.height {
+                (0, self.height - 1 - k)
+            } else {
+                (k + 1 - self.height, 0)
+            };
+            let len = (self.width - x).min(self.height - y);
+            (0..len).map(move |i| &self[(x + i, y + i)])
+        })
+    }
+
+    /// `/` diagonals, each from its bottom left end, starting at the top left
+    /// corner of the grid.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..(self.width + self.height - 1)).map(move |k| {
+            let (x, y) = if k < self.height {
+                (0, k)
+            } else {
+                (k + 1 - self.height, self.height - 1)
+            };
+            let len = (self.width - x).min(y + 1);
+            (0..len).map(move |i| &self[(x + i, y - i)])
+        })
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        match self.get(coord) {
+            Some(c) => c,
+            None => panic!("{coord:?} is out of a {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(coord) {
+            Some(c) => c,
+            None => panic!("{coord:?} is out of a {width}x{height} grid"),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                cell.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn grid(input: &str) -> Grid<char> {
+        let rows: Vec<_> = parse::str_lines(input).collect();
+        Grid::parse(&rows, Some, "a cell").unwrap()
+    }
+
+    #[test]
+    fn test_views() {
+        let g = grid("abc\ndef");
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g.get((2, 1)), Some(&'f'));
+        assert_eq!(g.get((3, 0)), None);
+        assert_eq!(g.column(1).collect::<String>(), "be");
+        let diags: Vec<String> = g.diagonals().map(Iterator::collect).collect();
+        assert_eq!(diags, ["d", "ae", "bf", "c"]);
+        let antis: Vec<String> = g.anti_diagonals().map(Iterator::collect).collect();
+        assert_eq!(antis, ["a", "db", "ec", "f"]);
+        assert_eq!(g.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let g = grid("abc\ndef");
+        let n: Vec<_> = g.neighbours4((0, 0)).collect();
+        assert_eq!(n, [(1, 0), (0, 1)]);
+        let n: Vec<_> = g.neighbours8((1, 1)).collect();
+        assert_eq!(n, [(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)]);
+        assert_eq!(g.step((1, 1), Vec2D::new(1, -1)), Some((2, 0)));
+        assert_eq!(g.step((2, 1), Vec2D::new(1, 0)), None);
+    }
+
+    #[test]
+    fn test_ragged() {
+        let rows: Vec<_> = parse::str_lines("abc\nde").collect();
+        let err = Grid::parse(&rows, Some, "a cell").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 3));
+    }
+}
@@ -0,0 +1,160 @@
+//! Export of [`Scene`]s as binary Netpbm images, which most image viewers
+//! open: PPM in colors, PBM in black and white.
+
+use std::str::FromStr;
+
+use crate::render::{Color, Scene};
+
+pub type Rgb = [u8; 3];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Pbm,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Pbm => "pbm",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ppm" => Ok(Format::Ppm),
+            "pbm" => Ok(Format::Pbm),
+            _ => Err(format!("IMAGE must be one of `ppm` or `pbm`, got `{s}`")),
+        }
+    }
+}
+
+/// Colors of the glyphs drawn, over those of the scene's overlays. Other
+/// cells are white for `.` and ` `, dark gray otherwise.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Palette {
+    glyphs: Vec<(char, Rgb)>,
+}
+
+impl Palette {
+    fn rgb(&self, glyph: char, fg: Option<Color>, bg: Option<Color>) -> Rgb {
+        if let Some((_, rgb)) = self.glyphs.iter().rev().find(|(g, _)| *g == glyph) {
+            return *rgb;
+        }
+        match (bg.or(fg), glyph) {
+            (Some(color), _) => color_rgb(color),
+            (None, '.' | ' ') => [0xff; 3],
+            (None, _) => [0x40; 3],
+        }
+    }
+}
+
+impl FromStr for Palette {
+    type Err = String;
+
+    /// Comma-separated `GLYPH=RRGGBB` entries, like `#=000000,O=00ff00`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let glyphs = s
+            .split(',')
+            .map(|entry| {
+                let invalid = || format!("Expected `GLYPH=RRGGBB`, got `{entry}`");
+                let mut chars = entry.chars();
+                let (Some(glyph), Some('=')) = (chars.next(), chars.next()) else {
+                    Err(invalid())?
+                };
+                let hex = chars.as_str();
+                if hex.len() != 6 {
+                    Err(invalid())?
+                }
+                let channel = |i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid());
+                Ok((glyph, [channel(0)?, channel(2)?, channel(4)?]))
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(Palette { glyphs })
+    }
+}
+
+fn color_rgb(color: Color) -> Rgb {
+    match color {
+        Color::Red => [0xd0, 0x20, 0x20],
+        Color::Green => [0x20, 0xa0, 0x20],
+        Color::Yellow => [0xe0, 0xb0, 0x00],
+        Color::Blue => [0x20, 0x40, 0xd0],
+        Color::Magenta => [0xb0, 0x20, 0xb0],
+        Color::Cyan => [0x00, 0xa0, 0xb0],
+    }
+}
+
+/// `scene` as an image of `scale` by `scale` pixels per cell. In PBM, cells
+/// darker than mid-gray are black.
+pub fn encode(scene: &Scene, format: Format, scale: usize, palette: &Palette) -> Vec<u8> {
+    let cells = scene.cells();
+    let (width, height) = (cells.width() * scale, cells.height() * scale);
+    let magic = match format {
+        Format::Ppm => "P6",
+        Format::Pbm => "P4",
+    };
+    let mut out = format!("{magic}\n{width} {height}\n").into_bytes();
+    if format == Format::Ppm {
+        out.extend(b"255\n");
+    }
+    for row in cells.rows() {
+        let colors: Vec<_> = row
+            .iter()
+            .map(|(glyph, style)| palette.rgb(*glyph, style.fg, style.bg))
+            .collect();
+        let line: Vec<u8> = match format {
+            Format::Ppm => colors.iter().flat_map(|rgb| rgb.repeat(scale)).collect(),
+            Format::Pbm => {
+                let pixels: Vec<bool> = colors
+                    .iter()
+                    .flat_map(|[r, g, b]| {
+                        let luma = 299 * *r as u32 + 587 * *g as u32 + 114 * *b as u32;
+                        std::iter::repeat_n(luma < 128_000, scale)
+                    })
+                    .collect();
+                // Rows are padded to whole bytes, the first pixel being the
+                // highest bit
+                pixels
+                    .chunks(8)
+                    .map(|bits| {
+                        bits.iter()
+                            .enumerate()
+                            .fold(0, |byte, (i, black)| byte | (u8::from(*black) << (7 - i)))
+                    })
+                    .collect()
+            }
+        };
+        for _ in 0..scale {
+            out.extend(&line);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    #[test]
+    fn test_encode() {
+        let grid = Grid::from_cells(2, vec!['#', '.']);
+        let scene = Scene::new(&grid, |c| *c);
+        let pbm = encode(&scene, Format::Pbm, 5, &Palette::default());
+        assert_eq!(pbm, b"P4\n10 5\n\xf8\x00\xf8\x00\xf8\x00\xf8\x00\xf8\x00");
+
+        let palette: Palette = "#=ff0000,.=0000ff".parse().unwrap();
+        let scene = scene.actor((1, 0), 'O', Color::Green);
+        let ppm = encode(&scene, Format::Ppm, 1, &palette);
+        assert_eq!(ppm, b"P6\n2 1\n255\n\xff\x00\x00\x20\xa0\x20");
+
+        assert!("#=ff00".parse::<Palette>().is_err());
+        assert!("#ff0000".parse::<Palette>().is_err());
+    }
+}
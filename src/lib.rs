@@ -0,0 +1,25 @@
+pub mod animate;
+mod answer;
+pub mod cancel;
+pub mod expected;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod log;
+pub mod parallel;
+pub mod params;
+pub mod parse;
+#[cfg(test)]
+mod property;
+pub mod puzzles;
+pub mod random;
+pub mod render;
+pub mod search;
+mod solution;
+
+pub use answer::Answer;
+pub use geometry::{Dir, Dir8, Vec2D};
+pub use grid::{Coord, Grid};
+pub use params::Params;
+pub use parse::ParseError;
+pub use solution::{input_path, open_input, Day, Part, RunError, Solution, Timing};
@@ -0,0 +1,228 @@
+//! Leveled diagnostics on stderr, off by default so they neither slow the
+//! solvers down nor mix with the answers.
+//!
+//! Messages are logged with [`error!`](crate::error), [`info!`](crate::info),
+//! [`debug!`](crate::debug) or [`trace!`](crate::trace) and targeted at their
+//! module, `dayN` for the `puzzle_N` ones. Which are printed is set once by
+//! [`init`] from directives like `debug` or `info,day12=trace`.
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        OnceLock,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 4] = [Level::Error, Level::Info, Level::Debug, Level::Trace];
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Level::Error => "ERROR",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        })
+    }
+}
+
+/// The most verbose level printed, `None` when off.
+fn parse_level(s: &str) -> Result<Option<Level>, String> {
+    match s {
+        "off" => Ok(None),
+        "error" => Ok(Some(Level::Error)),
+        "info" => Ok(Some(Level::Info)),
+        "debug" => Ok(Some(Level::Debug)),
+        "trace" => Ok(Some(Level::Trace)),
+        _ => Err(format!(
+            "Log level must be one of `off`, `error`, `info`, `debug` or `trace`, got `{s}`"
+        )),
+    }
+}
+
+/// Which messages get printed: a default level and overrides per target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    targets: Vec<(String, Option<Level>)>,
+}
+
+impl Default for Filter {
+    /// Only errors.
+    fn default() -> Self {
+        Filter {
+            default: Some(Level::Error),
+            targets: Vec::new(),
+        }
+    }
+}
+
+impl Filter {
+    /// Raises the default level by one per `-v`, from errors only.
+    pub fn verbose(mut self, count: usize) -> Self {
+        if count > 0 {
+            let level = Level::ALL[count.min(Level::ALL.len() - 1)];
+            self.default = self.default.max(Some(level));
+        }
+        self
+    }
+
+    fn level(&self, module: &str) -> Option<Level> {
+        self.targets
+            .iter()
+            .rev()
+            .find(|(target, _)| matches(target, module))
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    fn max(&self) -> Option<Level> {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Option::max)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    /// Comma-separated directives, each a level or `TARGET=LEVEL`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter::default();
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => filter
+                    .targets
+                    .push((target.to_owned(), parse_level(level)?)),
+                None => filter.default = parse_level(directive)?,
+            }
+        }
+        Ok(filter)
+    }
+}
+
+/// Whether `target`, as written in a directive, names the module path `module`.
+fn matches(target: &str, module: &str) -> bool {
+    let name = module.rsplit("::").next().unwrap_or(module);
+    match name.strip_prefix("puzzle_") {
+        Some(day) => target.strip_prefix("day") == Some(day),
+        None => target == name,
+    }
+}
+
+fn target(module: &str) -> String {
+    let name = module.rsplit("::").next().unwrap_or(module);
+    match name.strip_prefix("puzzle_") {
+        Some(day) => format!("day{day}"),
+        None => name.to_owned(),
+    }
+}
+
+static FILTER: OnceLock<Filter> = OnceLock::new();
+/// Most verbose level of [`FILTER`], checked first to keep disabled
+/// messages cheap in hot loops.
+static MAX: AtomicU8 = AtomicU8::new(Level::Error as u8);
+
+/// Sets which messages get printed, only errors until then. Later calls
+/// are ignored.
+pub fn init(filter: Filter) {
+    let max = filter.max().map_or(0, |l| l as u8);
+    if FILTER.set(filter).is_ok() {
+        MAX.store(max, Ordering::Relaxed);
+    }
+}
+
+/// Whether a message at `level` from the module path `module` is printed.
+pub fn enabled(level: Level, module: &str) -> bool {
+    if level as u8 > MAX.load(Ordering::Relaxed) {
+        return false;
+    }
+    match FILTER.get() {
+        Some(filter) => filter.level(module) >= Some(level),
+        None => level == Level::Error,
+    }
+}
+
+/// Prints a message the caller checked is [`enabled`], so that the
+/// arguments of filtered out messages are never evaluated.
+#[doc(hidden)]
+pub fn log(level: Level, module: &str, args: fmt::Arguments) {
+    eprintln!("[{level} {}] {args}", target(module));
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => {
+        if $crate::log::enabled($crate::log::Level::Error, module_path!()) {
+            $crate::log::log($crate::log::Level::Error, module_path!(), format_args!($($arg)+))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        if $crate::log::enabled($crate::log::Level::Info, module_path!()) {
+            $crate::log::log($crate::log::Level::Info, module_path!(), format_args!($($arg)+))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        if $crate::log::enabled($crate::log::Level::Debug, module_path!()) {
+            $crate::log::log($crate::log::Level::Debug, module_path!(), format_args!($($arg)+))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        if $crate::log::enabled($crate::log::Level::Trace, module_path!()) {
+            $crate::log::log($crate::log::Level::Trace, module_path!(), format_args!($($arg)+))
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let day12 = "aoc_2024::puzzles::puzzle_12";
+        let day1 = "aoc_2024::puzzles::puzzle_1";
+        let filter: Filter = "day12=trace".parse().unwrap();
+        assert_eq!(filter.level(day12), Some(Level::Trace));
+        assert_eq!(filter.level(day1), Some(Level::Error));
+        assert_eq!(filter.max(), Some(Level::Trace));
+
+        let filter: Filter = "debug,day1=off".parse().unwrap();
+        assert_eq!(filter.level(day12), Some(Level::Debug));
+        assert_eq!(filter.level(day1), None);
+        assert_eq!(filter.level("aoc_2024::search"), Some(Level::Debug));
+
+        assert_eq!(Filter::default().verbose(2).level(day1), Some(Level::Debug));
+        assert_eq!(Filter::default().verbose(9).level(day1), Some(Level::Trace));
+        assert_eq!(
+            "off".parse::<Filter>().unwrap().verbose(0).level(day1),
+            None
+        );
+        assert!("day12=loud".parse::<Filter>().is_err());
+        assert_eq!(target(day12), "day12");
+    }
+}
@@ -0,0 +1,88 @@
+//! Maps over independent items on several threads, for the searches that try
+//! each candidate on its own.
+
+use std::{
+    panic,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use crate::cancel;
+
+static THREADS: AtomicUsize = AtomicUsize::new(1);
+
+/// Sets how many threads [`map`] runs on, 1 mapping on the calling thread.
+pub fn set_threads(n: usize) {
+    THREADS.store(n.max(1), Ordering::Relaxed);
+}
+
+pub fn threads() -> usize {
+    THREADS.load(Ordering::Relaxed)
+}
+
+/// `f` of each item, in the order of `items` whatever the threads.
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    map_with(items, || (), |(), item| f(item))
+}
+
+/// Like [`map`], each thread passing its own `init()` state to `f`, like a
+/// cache.
+///
+/// Items are dealt in turn to the threads, which balances searches whose
+/// cost grows along the list.
+pub fn map_with<T: Sync, S, R: Send>(
+    items: &[T],
+    init: impl Fn() -> S + Sync,
+    f: impl Fn(&mut S, &T) -> R + Sync,
+) -> Vec<R> {
+    let threads = threads().min(items.len());
+    if threads <= 1 {
+        let mut state = init();
+        return items.iter().map(|item| f(&mut state, item)).collect();
+    }
+    // A cancelled run stops on every thread
+    let token = cancel::current();
+    let dealt: Vec<Vec<R>> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|first| {
+                let (init, f, token) = (&init, &f, token.clone());
+                scope.spawn(move || {
+                    cancel::install(token);
+                    let mut state = init();
+                    items
+                        .iter()
+                        .skip(first)
+                        .step_by(threads)
+                        .map(|item| f(&mut state, item))
+                        .collect()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    });
+    let mut dealt: Vec<_> = dealt.into_iter().map(Vec::into_iter).collect();
+    (0..items.len())
+        .map(|i| dealt[i % threads].next().expect("a result per item"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let items: Vec<u64> = (0..100).collect();
+        let sequential = map(&items, |n| n * n);
+        set_threads(7);
+        assert_eq!(map(&items, |n| n * n), sequential);
+        // Each thread sees every 7th item
+        let gaps = map_with(&items, || None, |last, n| last.replace(*n).map(|l| n - l));
+        assert!(gaps[7..].iter().all(|gap| *gap == Some(7)));
+        assert_eq!(map(&[1u8], |n| *n), [1]);
+        set_threads(1);
+    }
+}
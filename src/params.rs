@@ -0,0 +1,121 @@
+use std::str::FromStr;
+
+use crate::Vec2D;
+
+/// `NAME=VALUE` overrides of parameters, as given to `--param`.
+pub type Overrides = [(String, String)];
+
+/// Settings a day reads besides its input, like the size of its map, with
+/// defaults for the real inputs. Set from `--param NAME=VALUE` by the runner.
+pub trait Params: Clone + Default {
+    /// Name and value of each parameter.
+    fn values(&self) -> Vec<(&'static str, String)>;
+
+    /// Sets the parameter `name`, one of those in [`Params::values`], others
+    /// being an error.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+
+    /// The defaults overridden by `overrides`, rejecting unknown names.
+    fn with(overrides: &Overrides) -> Result<Self, String> {
+        let mut params = Self::default();
+        let known: Vec<_> = params.values().into_iter().map(|(n, _)| n).collect();
+        for (name, value) in overrides {
+            if !known.contains(&name.as_str()) {
+                Err(match known.as_slice() {
+                    [] => format!("Unknown parameter `{name}`, this day takes none"),
+                    _ => format!(
+                        "Unknown parameter `{name}`, expected one of `{}`",
+                        known.join("`, `")
+                    ),
+                })?
+            }
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+}
+
+impl Params for () {
+    fn values(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn set(&mut self, name: &str, _: &str) -> Result<(), String> {
+        Err(format!("Unknown parameter `{name}`"))
+    }
+}
+
+/// Parses the `value` of the parameter `name`.
+pub fn value<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value `{value}` for parameter `{name}`"))
+}
+
+/// Parses a `WIDTHxHEIGHT` size like `11x7`.
+pub fn size(name: &str, text: &str) -> Result<Vec2D, String> {
+    let invalid = || format!("Invalid size `{text}` for parameter `{name}`, expected `WxH`");
+    let (w, h) = text.split_once('x').ok_or_else(invalid)?;
+    match (w.parse(), h.parse()) {
+        (Ok(w), Ok(h)) if w > 0 && h > 0 => Ok(Vec2D::new(w, h)),
+        _ => Err(invalid()),
+    }
+}
+
+/// Splits a `NAME=VALUE` override.
+pub fn parse_override(text: &str) -> Result<(String, String), String> {
+    match text.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_owned(), value.to_owned())),
+        _ => Err(format!("Expected `NAME=VALUE`, got `{text}`")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Test {
+        size: Vec2D,
+        steps: u32,
+    }
+
+    impl Params for Test {
+        fn values(&self) -> Vec<(&'static str, String)> {
+            vec![
+                ("size", format!("{}x{}", self.size.x, self.size.y)),
+                ("steps", self.steps.to_string()),
+            ]
+        }
+
+        fn set(&mut self, name: &str, text: &str) -> Result<(), String> {
+            match name {
+                "size" => self.size = size(name, text)?,
+                "steps" => self.steps = value(name, text)?,
+                name => Err(format!("Unknown parameter `{name}`"))?,
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_with() {
+        let over =
+            |s: &[&str]| -> Vec<_> { s.iter().map(|o| parse_override(o).unwrap()).collect() };
+        let params = Test::with(&over(&["size=11x7", "steps=3"])).unwrap();
+        assert_eq!(params.values()[0].1, "11x7");
+        assert_eq!(params.steps, 3);
+        assert!(Test::with(&over(&["size=11"])).is_err());
+        assert_eq!(
+            Test::with(&over(&["time=1"])).unwrap_err(),
+            "Unknown parameter `time`, expected one of `size`, `steps`"
+        );
+        assert!(<()>::with(&over(&["a=b"])).is_err());
+        // Near misses are not taken for another parameter
+        assert_eq!(
+            Test::default().set("step", "3").unwrap_err(),
+            "Unknown parameter `step`"
+        );
+        assert!(parse_override("=3").is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use std::{
+    fmt::Display,
+    io::{self, BufRead},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Malformed puzzle input, pointing at the offending character.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    file: Option<PathBuf>,
+    line: usize,
+    column: usize,
+    text: String,
+    expected: String,
+}
+
+impl ParseError {
+    /// `line` and `column` are 1-based, `column` counts chars of `text`.
+    pub fn new(line: usize, column: usize, text: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line,
+            column,
+            text: text.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// The input stopped before `expected`, `line` being the first missing one.
+    pub fn eof(line: usize, expected: impl Into<String>) -> Self {
+        ParseError::new(line, 1, "", expected)
+    }
+
+    pub fn with_file(mut self, file: &Path) -> Self {
+        self.file = Some(file.to_owned());
+        self
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let found = match self.text.chars().nth(self.column.saturating_sub(1)) {
+            Some(c) => format!("`{c}`"),
+            None if self.text.is_empty() && self.column == 1 => "end of input".to_owned(),
+            None => "end of line".to_owned(),
+        };
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        writeln!(
+            f,
+            "{}:{}: expected {}, found {found}",
+            self.line, self.column, self.expected
+        )?;
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(
+            f,
+            "{gutter} | {}^",
+            " ".repeat(self.column.saturating_sub(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of input and its 1-based number, to build [`ParseError`]s from.
+#[derive(Clone, Debug)]
+pub struct Line {
+    pub number: usize,
+    pub text: String,
+}
+
+impl Line {
+    /// Error at the first char of `at`, which must be a slice of `self.text`;
+    /// an empty `at` points right after the end of the line.
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|o| *o <= self.text.len())
+            .unwrap_or(self.text.len());
+        let column = self.text[..offset].chars().count() + 1;
+        ParseError::new(self.number, column, &self.text, expected)
+    }
+
+    /// Empty slice at the end of the line, for "expected more" errors.
+    pub fn end(&self) -> &str {
+        &self.text[self.text.len()..]
+    }
+
+    pub fn error_at(&self, column: usize, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, column + 1, &self.text, expected)
+    }
+
+    /// Parses `field`, a slice of `self.text`, reporting `expected` on failure.
+    pub fn parse<T: FromStr>(&self, field: &str, expected: &str) -> Result<T, ParseError> {
+        field.parse().map_err(|_| {
+            let trimmed = field.trim_start();
+            self.error(if trimmed.is_empty() { field } else { trimmed }, expected)
+        })
+    }
+
+    /// Checks that the line is only made of chars from `allowed`.
+    pub fn only(&self, allowed: &str) -> Result<(), ParseError> {
+        match self.text.chars().position(|c| !allowed.contains(c)) {
+            Some(column) => {
+                let mut chars: Vec<String> = allowed.chars().map(|c| format!("`{c}`")).collect();
+                let last = chars.pop().unwrap_or_default();
+                let expected = match chars.len() {
+                    0 => last,
+                    _ => format!("one of {} or {last}", chars.join(", ")),
+                };
+                Err(self.error_at(column, expected))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Byte index of `pat` in the line, or an error at its end.
+    pub fn find(&self, pat: char) -> Result<usize, ParseError> {
+        self.text
+            .find(pat)
+            .ok_or_else(|| self.error(self.end(), format!("`{pat}`")))
+    }
+
+    /// Like [`Line::find`], searching only from byte `from` onwards.
+    pub fn find_from(&self, from: usize, pat: char) -> Result<usize, ParseError> {
+        self.text[from..]
+            .find(pat)
+            .map(|i| i + from)
+            .ok_or_else(|| self.error(self.end(), format!("`{pat}`")))
+    }
+}
+
+/// Numbers the lines of a reader, non UTF-8 lines being errors.
+pub fn lines<B: BufRead>(lines: io::Lines<B>) -> impl Iterator<Item = Result<Line, ParseError>> {
+    lines.enumerate().map(|(i, l)| match l {
+        Ok(text) => Ok(Line {
+            number: i + 1,
+            text,
+        }),
+        Err(_) => Err(ParseError::new(i + 1, 1, "", "valid UTF-8 text")),
+    })
+}
+
+/// Numbers the lines of an in-memory input.
+pub fn str_lines(input: &str) -> impl Iterator<Item = Line> + '_ {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text: text.to_owned(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_caret() {
+        let line = str_lines("1,2\n3,x4").nth(1).unwrap();
+        let err = line
+            .parse::<u64>(&line.text[2..], "a number")
+            .unwrap_err()
+            .with_file(Path::new("in.txt"));
+        assert_eq!((err.line(), err.column()), (2, 3));
+        assert_eq!(
+            err.to_string(),
+            "in.txt:2:3: expected a number, found `x`\n  |\n2 | 3,x4\n  |   ^"
+        );
+    }
+}
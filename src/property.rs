@@ -0,0 +1,170 @@
+//! Property checks on random values, any failure being shrunk to a minimal
+//! counterexample. Used to test solvers against naive references.
+
+use std::{
+    any::Any,
+    fmt::Debug,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::{random::Rng, Grid};
+
+/// Values that can be made smaller, to narrow a failure down.
+pub(crate) trait Shrink: Clone + Debug {
+    /// Smaller values, the biggest cuts first.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! shrink_int {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let mut smaller = vec![0, self / 2, self.saturating_sub(1)];
+                smaller.dedup();
+                smaller.retain(|s| s < self);
+                smaller
+            }
+        }
+    )*};
+}
+
+shrink_int!(u8, u64, usize);
+
+impl<A: Shrink, B: Shrink> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let firsts = self.0.shrink().into_iter().map(|a| (a, self.1.clone()));
+        let seconds = self.1.shrink().into_iter().map(|b| (self.0.clone(), b));
+        firsts.chain(seconds).collect()
+    }
+}
+
+impl<T: Shrink> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut smaller = Vec::new();
+        // Halves, then single items, then smaller items
+        if self.len() > 1 {
+            let half = self.len() / 2;
+            smaller.push(self[..half].to_vec());
+            smaller.push(self[half..].to_vec());
+        }
+        for i in 0..self.len() {
+            let mut v = self.clone();
+            v.remove(i);
+            smaller.push(v);
+        }
+        for (i, item) in self.iter().enumerate() {
+            for s in item.shrink() {
+                let mut v = self.clone();
+                v[i] = s;
+                smaller.push(v);
+            }
+        }
+        smaller
+    }
+}
+
+impl<T: Shrink> Shrink for Grid<T> {
+    /// Without a row or a column, then with a smaller cell.
+    fn shrink(&self) -> Vec<Self> {
+        let (width, height) = (self.width(), self.height());
+        let mut smaller = Vec::new();
+        if height > 1 {
+            for y in 0..height {
+                let cells = self.iter().filter(|(c, _)| c.1 != y);
+                smaller.push(Grid::from_cells(
+                    width,
+                    cells.map(|(_, t)| t.clone()).collect(),
+                ));
+            }
+        }
+        if width > 1 {
+            for x in 0..width {
+                let cells = self.iter().filter(|(c, _)| c.0 != x);
+                smaller.push(Grid::from_cells(
+                    width - 1,
+                    cells.map(|(_, t)| t.clone()).collect(),
+                ));
+            }
+        }
+        for (c, cell) in self.iter() {
+            for s in cell.shrink() {
+                let mut g = self.clone();
+                g[c] = s;
+                smaller.push(g);
+            }
+        }
+        smaller
+    }
+}
+
+/// Checks `property` on `cases` values from `generate`, seeded 0, 1 and so
+/// on. A property fails by returning an error or panicking, and the check
+/// then panics with the smallest failing value shrinking finds.
+pub(crate) fn check<T: Shrink>(
+    cases: u64,
+    generate: impl Fn(&mut Rng) -> T,
+    property: impl Fn(&T) -> Result<(), String>,
+) {
+    for seed in 0..cases {
+        let value = generate(&mut Rng::new(seed));
+        if let Err(error) = holds(&property, &value) {
+            let (value, error) = minimize(value, error, &property);
+            panic!("Failed for seed {seed}: {error}\nMinimal counterexample: {value:?}");
+        }
+    }
+}
+
+fn holds<T>(property: impl Fn(&T) -> Result<(), String>, value: &T) -> Result<(), String> {
+    panic::catch_unwind(AssertUnwindSafe(|| property(value)))
+        .unwrap_or_else(|payload| Err(format!("panicked: {}", message(payload.as_ref()))))
+}
+
+fn message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(s) => s,
+        None => payload.downcast_ref::<String>().map_or("?", |s| s.as_str()),
+    }
+}
+
+/// Greedily moves to the first smaller value still failing, until none does.
+fn minimize<T: Shrink>(
+    mut value: T,
+    mut error: String,
+    property: impl Fn(&T) -> Result<(), String>,
+) -> (T, String) {
+    'smaller: loop {
+        for smaller in value.shrink() {
+            if let Err(e) = holds(&property, &smaller) {
+                (value, error) = (smaller, e);
+                continue 'smaller;
+            }
+        }
+        return (value, error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimize() {
+        // No list sums over 100, failing first on one of 5 items up to 50
+        let property = |v: &Vec<u64>| match v.iter().sum::<u64>() {
+            0..=100 => Ok(()),
+            sum => Err(format!("sum {sum}")),
+        };
+        let generate = |rng: &mut Rng| (0..5).map(|_| rng.range(0..=50) as u64).collect();
+        let failing = (0..).find_map(|seed| {
+            let v: Vec<u64> = generate(&mut Rng::new(seed));
+            property(&v).err().map(|e| (v, e))
+        });
+        let (value, error) = failing.unwrap();
+        let (value, _) = minimize(value, error, property);
+        assert_eq!(value.iter().sum::<u64>(), 101);
+        assert!(value.iter().all(|n| *n > 0));
+
+        let result = panic::catch_unwind(|| check(100, generate, property));
+        assert!(result.is_err());
+    }
+}
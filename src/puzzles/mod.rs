@@ -0,0 +1,49 @@
+use crate::Day;
+
+pub mod puzzle_1;
+pub mod puzzle_10;
+pub mod puzzle_11;
+pub mod puzzle_12;
+pub mod puzzle_13;
+pub mod puzzle_14;
+pub mod puzzle_15;
+pub mod puzzle_16;
+pub mod puzzle_17;
+pub mod puzzle_18;
+pub mod puzzle_19;
+pub mod puzzle_2;
+pub mod puzzle_20;
+pub mod puzzle_3;
+pub mod puzzle_4;
+pub mod puzzle_5;
+pub mod puzzle_6;
+pub mod puzzle_7;
+pub mod puzzle_8;
+pub mod puzzle_9;
+
+pub const ALL: [Day; 20] = [
+    Day::new::<puzzle_1::Puzzle>(),
+    Day::new::<puzzle_2::Puzzle>(),
+    Day::new::<puzzle_3::Puzzle>(),
+    Day::new::<puzzle_4::Puzzle>(),
+    Day::new::<puzzle_5::Puzzle>(),
+    Day::new::<puzzle_6::Puzzle>(),
+    Day::new::<puzzle_7::Puzzle>(),
+    Day::new::<puzzle_8::Puzzle>(),
+    Day::new::<puzzle_9::Puzzle>(),
+    Day::new::<puzzle_10::Puzzle>(),
+    Day::new::<puzzle_11::Puzzle>(),
+    Day::new::<puzzle_12::Puzzle>(),
+    Day::new::<puzzle_13::Puzzle>(),
+    Day::new::<puzzle_14::Puzzle>(),
+    Day::new::<puzzle_15::Puzzle>(),
+    Day::new::<puzzle_16::Puzzle>(),
+    Day::new::<puzzle_17::Puzzle>(),
+    Day::new::<puzzle_18::Puzzle>(),
+    Day::new::<puzzle_19::Puzzle>(),
+    Day::new::<puzzle_20::Puzzle>(),
+];
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    ALL.iter().find(|d| d.day == day)
+}
@@ -0,0 +1,79 @@
+use std::{collections::HashMap, io::BufRead};
+
+use crate::{parse, random::Rng, Answer, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 1;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    left: Vec<u64>,
+    right: Vec<u64>,
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let (mut left, mut right) = (vec![], vec![]);
+    for line in parse::lines(lines) {
+        let line = line?;
+        let mut ids = line.text.split_ascii_whitespace();
+        for list in [&mut left, &mut right] {
+            let id = ids.next().unwrap_or(line.end());
+            list.push(line.parse(id, "a location ID")?);
+        }
+    }
+    Ok(PuzzleData { left, right })
+}
+
+/// `size` pairs of location IDs, the right list repeating some of the left.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let left: Vec<_> = (0..size).map(|_| rng.range(10_000..=99_999)).collect();
+    let mut lines = Vec::new();
+    for l in &left {
+        let r = if rng.chance(0.5) {
+            *rng.pick(&left)
+        } else {
+            rng.range(10_000..=99_999)
+        };
+        lines.push(format!("{l}   {r}\n"));
+    }
+    lines.concat()
+}
+
+fn part1(data: PuzzleData) -> u64 {
+    let (mut left, mut right) = (data.left, data.right);
+    left.sort_unstable();
+    right.sort_unstable();
+    left.into_iter()
+        .zip(right)
+        .map(|(l, r)| l.abs_diff(r))
+        .sum()
+}
+
+fn part2(data: PuzzleData) -> u64 {
+    let mut right = data.right.into_iter().fold(HashMap::new(), |mut acc, v| {
+        acc.entry(v).and_modify(|va| *va += 1u64).or_insert(1u64);
+        acc
+    });
+    data.left
+        .into_iter()
+        .map(|id| id * *right.entry(id).or_default())
+        .sum()
+}
@@ -0,0 +1,96 @@
+use std::{collections::HashMap, io::BufRead};
+
+use crate::{parse, random::Rng, search, Answer, Grid, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 10;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    heights: Grid<u8>,
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let rows = parse::lines(lines).collect::<Result<Vec<_>, _>>()?;
+    let heights = Grid::parse(&rows, |c| c.to_digit(16).map(|h| h as u8), "a height digit")?;
+    Ok(PuzzleData { heights })
+}
+
+/// A `size` by `size` map of random heights, with hiking trails climbing
+/// from 0 to 9 drawn over it.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let cells = (0..size * size).map(|_| rng.range(0..=9) as u8).collect();
+    let mut heights = Grid::from_cells(size, cells);
+    for _ in 0..(size * size / 20 + 1) {
+        let mut at = (rng.below(size), rng.below(size));
+        for h in 0..=9 {
+            heights[at] = h;
+            let next: Vec<_> = heights.neighbours4(at).collect();
+            at = *rng.pick(&next);
+        }
+    }
+    format!("{heights}\n")
+}
+
+fn part1(data: PuzzleData) -> u64 {
+    let heights = &data.heights;
+    let mut scores = 0;
+    let starts = heights.iter().filter_map(|(c, h)| h.eq(&0).then_some(c));
+    for start in starts {
+        let trails = search::bfs(start, move |&v| {
+            heights
+                .neighbours4(v)
+                .filter(move |n| heights[*n] == heights[v] + 1)
+        });
+        scores += trails.reached().filter(|(v, _)| heights[**v] == 9).count();
+    }
+    scores as u64
+}
+
+fn part2(data: PuzzleData) -> u64 {
+    let heights = data.heights;
+    let mut scores = 0;
+    let starts = heights.iter().filter_map(|(c, h)| h.eq(&0).then_some(c));
+    for start in starts {
+        let mut last_visited = HashMap::new();
+        last_visited.insert(start, 1u64);
+        for i in 1..10 {
+            let mut visited = HashMap::new();
+            for (coord, visits) in &last_visited {
+                heights
+                    .neighbours4(*coord)
+                    .filter(|n| heights[*n] == i)
+                    .for_each(|c| {
+                        visited
+                            .entry(c)
+                            .and_modify(|v| *v += *visits)
+                            .or_insert(*visits);
+                    });
+            }
+            last_visited.clear();
+            last_visited.extend(visited);
+        }
+
+        scores += last_visited.iter().fold(0, |acc, v| acc + v.1);
+    }
+    scores
+}
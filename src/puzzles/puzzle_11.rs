@@ -0,0 +1,140 @@
+use std::{collections::HashMap, io::BufRead};
+
+use crate::{params, parse, random::Rng, Answer, Params, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 11;
+    type Data = PuzzleData;
+    type Params = PuzzleParams;
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn set_params(data: &mut PuzzleData, params: &PuzzleParams) {
+        data.params = params.clone();
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    stones: Vec<u128>,
+    params: PuzzleParams,
+}
+
+/// Blinks of each part.
+#[derive(Clone, Debug)]
+pub struct PuzzleParams {
+    blinks1: usize,
+    blinks2: usize,
+}
+
+impl Default for PuzzleParams {
+    fn default() -> Self {
+        PuzzleParams {
+            blinks1: 25,
+            blinks2: 75,
+        }
+    }
+}
+
+impl Params for PuzzleParams {
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("blinks1", self.blinks1.to_string()),
+            ("blinks2", self.blinks2.to_string()),
+        ]
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "blinks1" => self.blinks1 = params::value(name, value)?,
+            "blinks2" => self.blinks2 = params::value(name, value)?,
+            name => Err(format!("Unknown parameter `{name}`"))?,
+        }
+        Ok(())
+    }
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let Some(line) = parse::lines(lines).next() else {
+        Err(ParseError::eof(1, "a line of stones"))?
+    };
+    let line = line?;
+    let stones = line
+        .text
+        .split_ascii_whitespace()
+        .map(|s| line.parse(s, "a stone number"))
+        .collect::<Result<_, _>>()?;
+
+    Ok(PuzzleData {
+        stones,
+        params: PuzzleParams::default(),
+    })
+}
+
+/// `size` stones, engraved with up to 7 digits.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let stones: Vec<_> = (0..size)
+        .map(|_| rng.range(0..=9_999_999).to_string())
+        .collect();
+    stones.join(" ") + "\n"
+}
+
+fn part1(data: PuzzleData) -> u64 {
+    let blinks = data.params.blinks1;
+    blink(data.stones, blinks)
+}
+
+fn part2(data: PuzzleData) -> u64 {
+    let blinks = data.params.blinks2;
+    blink(data.stones, blinks)
+}
+
+/// Number of stones after `blinks` blinks, counting stones by engraving.
+fn blink(stones: Vec<u128>, blinks: usize) -> u64 {
+    let mut stones = stones.into_iter().fold(HashMap::new(), |mut acc, stone| {
+        acc.entry(stone).and_modify(|v| *v += 1).or_insert(1);
+        acc
+    });
+
+    for _i in 0..blinks {
+        let mut new_stones = HashMap::new();
+        for (stone, n) in &stones {
+            if *stone == 0 {
+                new_stones.entry(1).and_modify(|v| *v += *n).or_insert(*n);
+            } else if stone.to_string().len() % 2 == 0 {
+                let s = stone.to_string();
+                let left = s[..(s.len() / 2)].parse().unwrap();
+                let right = s[(s.len() / 2)..].parse().unwrap();
+                new_stones
+                    .entry(left)
+                    .and_modify(|v| *v += *n)
+                    .or_insert(*n);
+                new_stones
+                    .entry(right)
+                    .and_modify(|v| *v += *n)
+                    .or_insert(*n);
+            } else {
+                new_stones
+                    .entry(stone * 2024)
+                    .and_modify(|v| *v += *n)
+                    .or_insert(*n);
+            }
+        }
+        stones.clear();
+        stones.extend(new_stones);
+    }
+
+    stones.into_iter().fold(0, |acc, v| acc + v.1)
+}
@@ -1,76 +1,74 @@
 use std::{
     collections::{HashMap, HashSet},
-    env,
-    fs::File as FileFs,
-    io::{BufRead, BufReader},
+    io::BufRead,
 };
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 3 {
-        eprintln!("Not enough arguments\nUSAGE: PART ./puzzle-12.exe FILE\n\tWhere PART is one of `1` or `2`");
-        return;
+use crate::{debug, parse, random::Rng, Answer, Coord, Grid, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 12;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
     }
-    let Ok(lines) = FileFs::open(args[2].clone()).map(|f| BufReader::new(f).lines()) else {
-        eprintln!("Error reading `{}`", args[2]);
-        return;
-    };
-    let data = parse_input(lines);
-    let sum: u64 = match args[1].as_str() {
-        "1" => part1(data),
-        "2" => part2(data),
-        _ => {
-            eprint!("Arguments invalid\nUSAGE: PART ./puzzle-12.exe FILE\n\tWhere PART must be one of `1` or `2`");
-            return;
-        }
-    };
-    println!("Got result `{sum}`!");
 }
 
 #[derive(Clone, Debug)]
-struct PuzzleData {
-    plots: Vec<char>,
-    width: usize,
+pub struct PuzzleData {
+    plots: Grid<char>,
 }
 
-fn parse_input(lines: std::io::Lines<BufReader<FileFs>>) -> PuzzleData {
-    let plots: Vec<char> = lines
-        .map(Result::unwrap)
-        .flat_map(|l| l.chars().collect::<Vec<char>>())
-        .collect();
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let rows = parse::lines(lines).collect::<Result<Vec<_>, _>>()?;
+    let plots = Grid::parse(&rows, Some, "a plant")?;
 
-    unsafe {
-        PuzzleData {
-            width: (plots.len() as f64).sqrt().to_int_unchecked(),
-            plots,
+    Ok(PuzzleData { plots })
+}
+
+/// A `size` by `size` garden of `size` rectangles of random plants painted
+/// over each other.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut plots = Grid::new(size, size, 'A');
+    for _ in 0..size {
+        let plant = char::from(b'A' + rng.below(26) as u8);
+        let (x, y) = (rng.below(size), rng.below(size));
+        let (w, h) = (rng.range(1..=(size / 3 + 1)), rng.range(1..=(size / 3 + 1)));
+        for py in y..(y + h).min(size) {
+            for px in x..(x + w).min(size) {
+                plots[(px, py)] = plant;
+            }
         }
     }
+    format!("{plots}\n")
 }
 
 impl PuzzleData {
-    // Order is Top Down Left Right
-    fn surrounding(&self, index: usize) -> Vec<(usize, char)> {
-        [
-            index
-                .ge(&self.width)
-                .then(|| (index - self.width, self.plots[index - self.width])),
-            index
-                .lt(&(self.width * (self.width - 1)))
-                .then(|| (index + self.width, self.plots[index + self.width])),
-            (index % self.width)
-                .gt(&0)
-                .then(|| (index - 1, self.plots[index - 1])),
-            (index % self.width)
-                .lt(&(self.width - 1))
-                .then(|| (index + 1, self.plots[index + 1])),
-        ]
-        .into_iter()
-        .flatten()
-        .collect()
+    fn surrounding(&self, coord: Coord) -> impl Iterator<Item = (Coord, char)> + '_ {
+        self.plots.neighbours4(coord).map(|n| (n, self.plots[n]))
     }
 
-    fn index_to_coord(&self, index: usize) -> (usize, usize) {
-        (index % self.width, index / self.width)
+    fn unassigned(&self, assigned: &HashSet<Coord>) -> (Coord, char) {
+        self.plots
+            .iter()
+            .find_map(|(c, p)| (!assigned.contains(&c)).then_some((c, *p)))
+            .unwrap()
+    }
+
+    fn len(&self) -> usize {
+        self.plots.cells().len()
     }
 }
 
@@ -230,13 +228,8 @@ impl Edge {
 fn part1(data: PuzzleData) -> u64 {
     let mut assigned = HashSet::new();
     let mut sum = 0;
-    while assigned.len() != data.plots.len() {
-        let (start, start_tag) = data
-            .plots
-            .iter()
-            .enumerate()
-            .find_map(|(i, p)| (!assigned.contains(&i)).then_some((i, *p)))
-            .unwrap();
+    while assigned.len() != data.len() {
+        let (start, start_tag) = data.unassigned(&assigned);
         let mut region = HashSet::from([start]);
         let mut visited = HashSet::from([start]);
         let mut last_len = 0;
@@ -247,18 +240,15 @@ fn part1(data: PuzzleData) -> u64 {
             let mut new_visited = HashMap::new();
             for p in &visited {
                 data.surrounding(*p)
-                    .into_iter()
                     .filter_map(|(i, c)| c.eq(&start_tag).then_some(i))
                     .for_each(|i| {
                         new_visited.entry(i).and_modify(|v| *v += 1).or_insert(1);
                     });
             }
-            // println!("{new_visited:?}");
             for n in new_visited
                 .iter()
                 .filter_map(|v| (!region.contains(v.0)).then_some(v.1))
             {
-                // println!("{area} - {perimeter}");
                 area += 1;
                 perimeter = perimeter
                     .checked_add_signed(match n {
@@ -275,7 +265,7 @@ fn part1(data: PuzzleData) -> u64 {
             region.extend(new_visited.keys());
             visited.extend(new_visited.keys());
         }
-        println!(
+        debug!(
             "Region {}: {}*{}={} {:?}",
             start_tag,
             area,
@@ -292,35 +282,25 @@ fn part1(data: PuzzleData) -> u64 {
 fn part2(data: PuzzleData) -> u64 {
     let mut assigned = HashSet::new();
     let mut sum = 0;
-    let mut i = 0;
-    while assigned.len() != data.plots.len() {
-        i += 1;
-        let (start, start_tag) = data
-            .plots
-            .iter()
-            .enumerate()
-            .find_map(|(i, p)| (!assigned.contains(&i)).then_some((i, *p)))
-            .unwrap();
+    while assigned.len() != data.len() {
+        let (start, start_tag) = data.unassigned(&assigned);
         let mut region = HashSet::from([start]);
         let mut area = 1;
-        let mut region_edges = Edge::plot_edges(data.index_to_coord(start));
+        let mut region_edges = Edge::plot_edges(start);
         let mut to_visit = vec![start];
         while let Some(p) = to_visit.pop() {
-            // println!("{region_edges:?}");
-            
             let new_plots = data
                 .surrounding(p)
-                .into_iter()
                 .filter_map(|(i, c)| (c.eq(&start_tag) && !region.contains(&i)).then_some(i))
                 .collect::<Vec<_>>();
             for i in new_plots {
                 to_visit.push(i);
                 region.insert(i);
                 area += 1;
-                add_plot(&mut region_edges, data.index_to_coord(i));
+                add_plot(&mut region_edges, i);
             }
         }
-        println!(
+        debug!(
             "Region {}: {}*{}={}\n{:?}\n{:?}\n",
             start_tag,
             area,
@@ -331,22 +311,16 @@ fn part2(data: PuzzleData) -> u64 {
         );
         sum += area * region_edges.len();
         assigned.extend(region);
-        if i > 0 {
-            // break;
-        }
     }
     sum as u64
 }
 
 fn add_plot(edges: &mut Vec<Edge>, coord: (usize, usize)) {
-    // println!("b {coord:?}: {edges:?}");
     let new_edges = Edge::plot_edges(coord);
     for edge in new_edges {
-        // print!("{edge:?} - ");
         if let Some(i) = edges.iter().position(|e| e.contains(&edge)) {
             let e = edges.remove(i);
             let new_edges = e.sub(&edge);
-            // println!("1 {e:?} => {new_edges:?}");
             edges.extend_from_slice(&new_edges);
         } else {
             let mut extendable = edges
@@ -356,24 +330,98 @@ fn add_plot(edges: &mut Vec<Edge>, coord: (usize, usize)) {
                 .collect::<Vec<_>>();
             if extendable.is_empty() {
                 edges.push(edge);
-                // println!("3");
             } else {
-                // println!("2");
                 let other = extendable.get(1).map(|e| (e.0, e.1.clone()));
                 let base = extendable.first_mut().unwrap();
                 base.1.extend(&edge);
-                let other = match other {
-                    Some(e) => {
-                        base.1.extend(&e.1);
-                        Some(e.0)
+                if let Some(e) = other {
+                    base.1.extend(&e.1);
+                    edges.remove(e.0);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{property, Dir};
+
+    /// Area and fenced sides of each region, by flood fill.
+    fn reference_regions(plots: &Grid<char>) -> Vec<(usize, Vec<(Coord, Dir)>)> {
+        let mut seen = HashSet::new();
+        let mut regions = Vec::new();
+        for (start, plant) in plots.iter() {
+            if !seen.insert(start) {
+                continue;
+            }
+            let (mut area, mut fences, mut stack) = (0, Vec::new(), vec![start]);
+            while let Some(c) = stack.pop() {
+                area += 1;
+                for dir in Dir::ALL {
+                    match dir.step(c).filter(|n| plots.get(*n) == Some(plant)) {
+                        Some(n) => {
+                            if seen.insert(n) {
+                                stack.push(n);
+                            }
+                        }
+                        None => fences.push((c, dir)),
                     }
-                    None => None,
-                };
-                if other.is_some() {
-                    edges.remove(other.unwrap());
                 }
             }
+            regions.push((area, fences));
         }
+        regions
+    }
+
+    fn reference_part1(plots: &Grid<char>) -> u64 {
+        let regions = reference_regions(plots).into_iter();
+        regions
+            .map(|(area, fences)| (area * fences.len()) as u64)
+            .sum()
+    }
+
+    /// Part 2, counting the fences starting a side: those with no fence
+    /// facing the same way before them, a turn to the left.
+    fn reference_part2(plots: &Grid<char>) -> u64 {
+        let regions = reference_regions(plots).into_iter();
+        regions
+            .map(|(area, fences)| {
+                let starts = fences.iter().filter(|(c, dir)| {
+                    let before = dir.turn_left().step(*c);
+                    !before.is_some_and(|b| fences.contains(&(b, *dir)))
+                });
+                (area * starts.count()) as u64
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_prices() {
+        property::check(
+            300,
+            |rng| {
+                let (width, height) = (rng.range(1..=5), rng.range(1..=5));
+                let cells = (0..width * height).map(|_| rng.below(3) as u8);
+                Grid::from_cells(width, cells.collect())
+            },
+            |plants: &Grid<u8>| {
+                let plots = Grid::from_cells(
+                    plants.width(),
+                    plants
+                        .cells()
+                        .iter()
+                        .map(|p| char::from(b'A' + p % 3))
+                        .collect(),
+                );
+                let expected = (reference_part1(&plots), reference_part2(&plots));
+                let data = PuzzleData { plots };
+                match (part1(data.clone()), part2(data.clone())) {
+                    prices if prices == expected => Ok(()),
+                    prices => Err(format!("{}: {prices:?}, not {expected:?}", data.plots)),
+                }
+            },
+        );
     }
-    // println!("a: {edges:?}");
 }
@@ -0,0 +1,282 @@
+use std::io::BufRead;
+
+use crate::{
+    debug, parallel, params,
+    parse::{self, Line},
+    random::Rng,
+    Answer, Params, ParseError, Solution,
+};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 13;
+    type Data = PuzzleData;
+    type Params = PuzzleParams;
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn set_params(data: &mut PuzzleData, params: &PuzzleParams) {
+        data.params = params.clone();
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Machine {
+    a: (usize, usize),
+    b: (usize, usize),
+    prize: (usize, usize),
+}
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    machines: Vec<Machine>,
+    params: PuzzleParams,
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleParams {
+    /// Added to both prize coordinates in part 2.
+    offset: usize,
+}
+
+impl Default for PuzzleParams {
+    fn default() -> Self {
+        PuzzleParams {
+            offset: 10000000000000,
+        }
+    }
+}
+
+impl Params for PuzzleParams {
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![("offset", self.offset.to_string())]
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "offset" => self.offset = params::value(name, value)?,
+            name => Err(format!("Unknown parameter `{name}`"))?,
+        }
+        Ok(())
+    }
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let mut machines = Vec::new();
+    let mut curr_m = Machine::default();
+    let mut state = 'a';
+    let mut last_line = 0;
+    for line in parse::lines(lines) {
+        let line = line?;
+        last_line = line.number;
+        match state {
+            'a' => {
+                curr_m.a = coords(&line, '+')?;
+                state = 'b';
+            }
+            'b' => {
+                curr_m.b = coords(&line, '+')?;
+                state = 'p';
+            }
+            'p' => {
+                curr_m.prize = coords(&line, '=')?;
+                machines.push(curr_m);
+                curr_m = Machine::default();
+                state = 'l';
+            }
+            'l' => state = 'a',
+            _ => unreachable!(),
+        };
+    }
+    match state {
+        'b' => Err(ParseError::eof(last_line + 1, "a `Button B` line")),
+        'p' => Err(ParseError::eof(last_line + 1, "a `Prize` line")),
+        _ => Ok(PuzzleData {
+            machines,
+            params: PuzzleParams::default(),
+        }),
+    }
+}
+
+/// `size` claw machines, half of whose prizes can be won.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut machines = Vec::new();
+    for _ in 0..size {
+        let a = (rng.range(10..=99), rng.range(10..=99));
+        // Like the puzzle's, buttons never push the same way
+        let b = loop {
+            let b = (rng.range(10..=99), rng.range(10..=99));
+            if a.0 * b.1 != a.1 * b.0 {
+                break b;
+            }
+        };
+        let prize = if rng.chance(0.5) {
+            let (na, nb) = (rng.range(0..=100), rng.range(0..=100));
+            (na * a.0 + nb * b.0, na * a.1 + nb * b.1)
+        } else {
+            (rng.range(1_000..=20_000), rng.range(1_000..=20_000))
+        };
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+    machines.join("\n")
+}
+
+// Reads `X<sep>.., Y<sep>..` from a button or prize line.
+fn coords(line: &Line, sep: char) -> Result<(usize, usize), ParseError> {
+    let l = line.text.as_str();
+    let x = line.find(sep)?;
+    let comma = line.find_from(x, ',')?;
+    let y = line.find_from(comma, sep)?;
+    Ok((
+        line.parse(&l[(x + 1)..comma], "a number")?,
+        line.parse(l[(y + 1)..].trim(), "a number")?,
+    ))
+}
+
+fn part1(data: PuzzleData) -> u64 {
+    parallel::map(&data.machines, tokens)
+        .into_iter()
+        .sum::<usize>() as u64
+}
+
+fn part2(mut data: PuzzleData) -> u64 {
+    for machine in &mut data.machines {
+        machine.prize.0 += data.params.offset;
+        machine.prize.1 += data.params.offset;
+    }
+    parallel::map(&data.machines, tokens)
+        .into_iter()
+        .sum::<usize>() as u64
+}
+
+/// Tokens spent winning the prize of `machine`, 0 if it cannot be won.
+fn tokens(machine: &Machine) -> usize {
+    let Machine { a, b, prize } = machine;
+    let det = (a.0 * b.1) as isize - (a.1 * b.0) as isize;
+    let res = if det == 0 {
+        collinear_presses(machine)
+    } else {
+        let na_i = (prize.0 * b.1) as isize - (prize.1 * b.0) as isize;
+        let nb_i = (prize.1 * a.0) as isize - (a.1 * prize.0) as isize;
+        let (na, nb) = (na_i / det, nb_i / det);
+        (na_i % det == 0 && nb_i % det == 0 && na >= 0 && nb >= 0)
+            .then_some((na as usize, nb as usize))
+    };
+    let tokens = res.map_or(0, |(a, b)| 3 * a + b);
+    debug!("{tokens}<={machine:?}");
+    tokens
+}
+
+/// The cheapest presses when both buttons push the same way, which leaves a
+/// single equation along it: as many presses as possible of the button
+/// giving more per token, then trading them for the other one until the
+/// rest divides.
+fn collinear_presses(machine: &Machine) -> Option<(usize, usize)> {
+    let Machine { a, b, prize } = machine;
+    // Along X, unless neither button moves that way
+    let along = |p: &(usize, usize)| if a.0 + b.0 > 0 { p.0 } else { p.1 };
+    let (cheap, other, swapped) = match along(a) > 3 * along(b) {
+        true => (along(a), along(b), false),
+        false => (along(b), along(a), true),
+    };
+    let presses = match along(prize).checked_div(cheap) {
+        // Neither button moves the claw
+        None => (0, 0),
+        // Past `cheap` presses of the other button, the same remainders
+        // repeat
+        Some(max) => {
+            let n = (max.saturating_sub(other)..=max)
+                .rev()
+                .find(|n| (along(prize) - n * cheap).is_multiple_of(other))?;
+            // A button going nowhere is never worth a press
+            let rest = (along(prize) - n * cheap).checked_div(other).unwrap_or(0);
+            if swapped {
+                (rest, n)
+            } else {
+                (n, rest)
+            }
+        }
+    };
+    // The other axis follows, if the prize is on the buttons' line
+    let (na, nb) = presses;
+    (na * a.0 + nb * b.0 == prize.0 && na * a.1 + nb * b.1 == prize.1).then_some(presses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property;
+
+    /// The fewest tokens over every number of A presses.
+    fn reference_tokens(machine: &Machine) -> usize {
+        let Machine { a, b, prize } = machine;
+        let most_a = match a {
+            (0, 0) => 0,
+            (x, y) => {
+                let most = |p: usize, d: usize| p.checked_div(d).unwrap_or(usize::MAX);
+                most(prize.0, *x).min(most(prize.1, *y))
+            }
+        };
+        let presses = (0..=most_a).filter_map(|na| {
+            let rest = (prize.0 - na * a.0, prize.1 - na * a.1);
+            let nb = match b.0 {
+                0 => rest.1.checked_div(b.1).unwrap_or(0),
+                x => rest.0 / x,
+            };
+            (rest == (nb * b.0, nb * b.1)).then_some(3 * na + nb)
+        });
+        presses.min().unwrap_or(0)
+    }
+
+    #[test]
+    fn test_tokens() {
+        type Buttons = ((u8, u8), (u8, u8));
+        property::check(
+            1000,
+            |rng| {
+                // Buttons may not move the claw along an axis, or at all
+                let a = (rng.range(0..=9), rng.range(0..=9));
+                // Half the time both buttons push the same way
+                let b = match rng.chance(0.5) {
+                    true => (rng.range(0..=9), rng.range(0..=9)),
+                    false => {
+                        let (k, l) = (rng.range(1..=3), rng.range(1..=3));
+                        (a.0 * k / l, a.1 * k / l)
+                    }
+                };
+                let (na, nb) = (rng.range(0..=30), rng.range(0..=30));
+                let mut prize = (na * a.0 + nb * b.0, na * a.1 + nb * b.1);
+                if rng.chance(0.2) {
+                    prize.0 += 1;
+                }
+                let button = |(x, y): (usize, usize)| (x as u8, y as u8);
+                ((button(a), button(b)), prize)
+            },
+            |&((a, b), prize): &(Buttons, (usize, usize))| {
+                let button = |(x, y): (u8, u8)| (usize::from(x), usize::from(y));
+                let machine = Machine {
+                    a: button(a),
+                    b: button(b),
+                    prize,
+                };
+                match (tokens(&machine), reference_tokens(&machine)) {
+                    (t, expected) if t == expected => Ok(()),
+                    (t, expected) => Err(format!("{t} tokens, not {expected}")),
+                }
+            },
+        );
+    }
+}
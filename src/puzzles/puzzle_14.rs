@@ -0,0 +1,242 @@
+use std::io::BufRead;
+
+use crate::{
+    animate::{Frames, Replay, Simulation},
+    cancel, debug, params, parse,
+    random::Rng,
+    render::{Color, Scene},
+    Answer, Grid, Params, ParseError, Part, Solution, Vec2D,
+};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 14;
+    type Data = PuzzleData;
+    type Params = PuzzleParams;
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn set_params(data: &mut PuzzleData, params: &PuzzleParams) {
+        data.params = params.clone();
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+    /// Part 2 is drawn on the second of the tree, unless there is none.
+    fn render(mut data: PuzzleData, part: Part) -> Option<Scene> {
+        let PuzzleParams { size, time } = data.params;
+        let time = match part {
+            Part::One => time,
+            Part::Two => part2(data.clone())? as isize,
+        };
+        elapse(&mut data.robots, size, time);
+        Some(scene(&data.robots, size))
+    }
+    /// One frame per second, up to `time` for part 1 and until the robots
+    /// are back where they started for part 2.
+    fn animate(data: PuzzleData, part: Part) -> Option<Box<dyn Frames>> {
+        let PuzzleParams { size, time } = data.params;
+        let seconds = match part {
+            Part::One => time.max(0) as usize,
+            Part::Two => (size.x * size.y) as usize,
+        };
+        Some(Box::new(Replay::new(Robots {
+            robots: data.robots,
+            size,
+            seconds,
+        })))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Robot {
+    p: Vec2D,
+    v: Vec2D,
+}
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    robots: Vec<Robot>,
+    params: PuzzleParams,
+}
+
+/// The examples are `size=11x7`.
+#[derive(Clone, Debug)]
+pub struct PuzzleParams {
+    size: Vec2D,
+    /// Seconds waited in part 1.
+    time: isize,
+}
+
+impl Default for PuzzleParams {
+    fn default() -> Self {
+        PuzzleParams {
+            size: Vec2D::new(101, 103),
+            time: 100,
+        }
+    }
+}
+
+impl Params for PuzzleParams {
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("size", format!("{}x{}", self.size.x, self.size.y)),
+            ("time", self.time.to_string()),
+        ]
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "size" => self.size = params::size(name, value)?,
+            "time" => self.time = params::value(name, value)?,
+            name => Err(format!("Unknown parameter `{name}`"))?,
+        }
+        Ok(())
+    }
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let robots = parse::lines(lines)
+        .map(|line| {
+            let line = line?;
+            let l = line.text.as_str();
+            let p = line.find('=')?;
+            let cp = line.find_from(p, ',')?;
+            let sep = line.find_from(cp, ' ')?;
+            let v = line.find_from(sep, '=')?;
+            let cv = line.find_from(v, ',')?;
+            Ok(Robot {
+                p: Vec2D::new(
+                    line.parse(&l[(p + 1)..cp], "a position")?,
+                    line.parse(&l[(cp + 1)..sep], "a position")?,
+                ),
+                v: Vec2D::new(
+                    line.parse(&l[(v + 1)..cv], "a velocity")?,
+                    line.parse(&l[(cv + 1)..], "a velocity")?,
+                ),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(PuzzleData {
+        robots,
+        params: PuzzleParams::default(),
+    })
+}
+
+/// `size` robots on the default map.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let map = PuzzleParams::default().size;
+    (0..size)
+        .map(|_| {
+            format!(
+                "p={},{} v={},{}\n",
+                rng.signed(0..=(map.x as i64 - 1)),
+                rng.signed(0..=(map.y as i64 - 1)),
+                rng.signed(-99..=99),
+                rng.signed(-99..=99),
+            )
+        })
+        .collect()
+}
+
+/// Moves the robots `time` seconds ahead on a map of `size`.
+fn elapse(robots: &mut [Robot], size: Vec2D, time: isize) {
+    for rob in robots {
+        let v = rob.v * time;
+        let s = rob.p + v;
+        let r = s % size;
+        rob.p = r;
+    }
+}
+
+/// The number of robots on each tile, like the puzzle draws them.
+fn scene(robots: &[Robot], size: Vec2D) -> Scene {
+    let mut counts = Grid::new(size.x as usize, size.y as usize, 0);
+    for rob in robots {
+        counts[(rob.p.x as usize, rob.p.y as usize)] += 1;
+    }
+    let occupied: Vec<_> = counts
+        .iter()
+        .filter(|(_, n)| **n > 0)
+        .map(|(c, _)| c)
+        .collect();
+    Scene::new(&counts, |n| match n {
+        0 => '.',
+        n => char::from_digit((*n).min(9), 10).expect("a count up to 9"),
+    })
+    .highlight(occupied, Color::Green)
+}
+
+/// The robots moving one second at a time.
+#[derive(Clone)]
+struct Robots {
+    robots: Vec<Robot>,
+    size: Vec2D,
+    /// Left to simulate.
+    seconds: usize,
+}
+
+impl Simulation for Robots {
+    fn step(&mut self) -> bool {
+        if self.seconds == 0 {
+            return false;
+        }
+        self.seconds -= 1;
+        elapse(&mut self.robots, self.size, 1);
+        true
+    }
+
+    fn scene(&self) -> Scene {
+        scene(&self.robots, self.size)
+    }
+}
+
+fn part1(mut data: PuzzleData) -> u64 {
+    let PuzzleParams { size, time } = data.params;
+    elapse(&mut data.robots, size, time);
+    let mut quadrants = [0, 0, 0, 0];
+    let mids = size / 2;
+    for rob in data.robots {
+        if rob.p.x < mids.x && rob.p.y < mids.y {
+            quadrants[0] += 1;
+        } else if rob.p.x > mids.x && rob.p.y < mids.y {
+            quadrants[1] += 1;
+        } else if rob.p.x > mids.x && rob.p.y > mids.y {
+            quadrants[2] += 1;
+        } else if rob.p.x < mids.x && rob.p.y > mids.y {
+            quadrants[3] += 1;
+        }
+    }
+
+    quadrants.into_iter().product::<i32>() as u64
+}
+
+/// The first second no two robots share a tile, which is when they draw
+/// the tree, `None` if they never do before being back where they
+/// started.
+fn part2(mut data: PuzzleData) -> Option<u64> {
+    let size = data.params.size;
+    for second in 1..=(size.x * size.y) as u64 {
+        cancel::check();
+        let mut taken = Grid::new(size.x as usize, size.y as usize, false);
+        let mut apart = true;
+        for rob in &mut data.robots {
+            rob.p = (rob.p + rob.v) % size;
+            let cell = (rob.p.x as usize, rob.p.y as usize);
+            apart &= !taken[cell];
+            taken[cell] = true;
+        }
+        if apart {
+            debug!("Second {second}:\n{}", scene(&data.robots, size));
+            return Some(second);
+        }
+    }
+    None
+}
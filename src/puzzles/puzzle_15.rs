@@ -0,0 +1,352 @@
+use std::{collections::HashSet, hash::RandomState, io::BufRead};
+
+use crate::{
+    animate::{Frames, Replay, Simulation},
+    parse,
+    random::Rng,
+    render::{Color, Scene},
+    trace, Answer, Coord, Dir, Grid, ParseError, Part, Solution,
+};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 15;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+    fn render(data: PuzzleData, part: Part) -> Option<Scene> {
+        Some(match part {
+            Part::One => warehouse(data).run().scene(),
+            Part::Two => wide_warehouse(data).run().scene(),
+        })
+    }
+    fn animate(data: PuzzleData, part: Part) -> Option<Box<dyn Frames>> {
+        Some(match part {
+            Part::One => Box::new(Replay::new(warehouse(data))),
+            Part::Two => Box::new(Replay::new(wide_warehouse(data))),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Copy)]
+enum Tile {
+    Wall,
+    Free,
+    Box,
+}
+#[derive(Clone, Debug, Copy)]
+enum TileD {
+    Wall,
+    Free,
+    BoxLeft,
+    BoxRight,
+}
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '.' | '@' => Self::Free,
+            '#' => Self::Wall,
+            'O' => Self::Box,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<TileD> for char {
+    fn from(value: TileD) -> Self {
+        match value {
+            TileD::Wall => '#',
+            TileD::Free => '.',
+            TileD::BoxLeft => '[',
+            TileD::BoxRight => ']',
+        }
+    }
+}
+impl From<Tile> for char {
+    fn from(value: Tile) -> Self {
+        match value {
+            Tile::Wall => '#',
+            Tile::Free => '.',
+            Tile::Box => 'O',
+        }
+    }
+}
+
+/// The warehouse is walled in, so nothing ever steps off the map.
+fn step(m: Dir, pos: Coord) -> Coord {
+    m.step(pos).expect("walls to surround the warehouse")
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    terrain: Grid<Tile>,
+    position: Coord,
+    moves: Vec<Dir>,
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let mut rows = Vec::new();
+    let mut moves = Vec::new();
+    let mut reading_moves = false;
+    for line in parse::lines(lines) {
+        let line = line?;
+        if line.text.is_empty() {
+            reading_moves = true;
+            continue;
+        }
+        if !reading_moves {
+            rows.push(line);
+        } else {
+            for (x, c) in line.text.chars().enumerate() {
+                let Some(m) = Dir::from_arrow(c) else {
+                    Err(line.error_at(x, "one of `<`, `>`, `^` or `v`"))?
+                };
+                moves.push(m);
+            }
+        }
+    }
+    let map = Grid::parse(
+        &rows,
+        |c| "#.O@".contains(c).then_some(c),
+        "one of `#`, `.`, `O` or `@`",
+    )?;
+    let Some(start) = map.find(|c| *c == '@') else {
+        Err(ParseError::eof(rows.len() + 1, "a robot `@` on the map"))?
+    };
+
+    Ok(PuzzleData {
+        terrain: map.map(|c| Tile::from(*c)),
+        position: start,
+        moves,
+    })
+}
+
+/// A walled `size` by `size` warehouse, a fifth of it boxes, then 8 moves per
+/// tile, 1000 to a line.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let cells = (0..size * size)
+        .map(|_| match rng.below(20) {
+            0 => '#',
+            1..=4 => 'O',
+            _ => '.',
+        })
+        .collect();
+    let mut map = Grid::from_cells(size, cells);
+    for c in map.coords().collect::<Vec<_>>() {
+        if c.0 == 0 || c.1 == 0 || c.0 == size - 1 || c.1 == size - 1 {
+            map[c] = '#';
+        }
+    }
+    map[(rng.range(1..=(size - 2)), rng.range(1..=(size - 2)))] = '@';
+    let moves: Vec<_> = (0..8 * size * size)
+        .map(|_| rng.pick(&Dir::ALL).arrow())
+        .collect();
+    let lines: Vec<String> = moves.chunks(1000).map(|l| l.iter().collect()).collect();
+    format!("{map}\n\n{}\n", lines.join("\n"))
+}
+
+/// Shifts the boxes from `next` onwards one step along `m`, if they are
+/// followed by a free tile before any wall.
+fn push_line<T>(terrain: &mut Grid<T>, next: Coord, m: Dir, kind: impl Fn(&T) -> Tile) -> bool {
+    let mut line = vec![next];
+    loop {
+        let last = line[line.len() - 1];
+        match kind(&terrain[last]) {
+            Tile::Wall => return false,
+            Tile::Free => break,
+            Tile::Box => line.push(step(m, last)),
+        }
+    }
+    for pair in line.windows(2).rev() {
+        terrain.swap(pair[0], pair[1]);
+    }
+    true
+}
+
+/// Moves the robot one step along `m` in the first warehouse.
+fn move_narrow(terrain: &mut Grid<Tile>, position: &mut Coord, m: Dir) {
+    let next = step(m, *position);
+    if push_line(terrain, next, m, |t| *t) {
+        *position = next;
+    }
+}
+
+/// Moves the robot one step along `m` in the warehouse twice as wide.
+fn move_wide(terrain: &mut Grid<TileD>, position: &mut Coord, m: Dir) {
+    let next = step(m, *position);
+    match &terrain[next] {
+        TileD::Wall => (),
+        TileD::Free => *position = next,
+        TileD::BoxLeft | TileD::BoxRight => match m {
+            Dir::Right | Dir::Left => {
+                let pushed = push_line(terrain, next, m, |t| match t {
+                    TileD::Wall => Tile::Wall,
+                    TileD::Free => Tile::Free,
+                    TileD::BoxLeft | TileD::BoxRight => Tile::Box,
+                });
+                if pushed {
+                    *position = next;
+                }
+            }
+            Dir::Up | Dir::Down => {
+                let mut all_tiles: HashSet<Coord> = HashSet::new();
+                let mut tiles_to_move = Vec::new();
+                tiles_to_move.push(next);
+                if matches!(&terrain[next], TileD::BoxRight) {
+                    tiles_to_move.push((next.0 - 1, next.1));
+                } else {
+                    tiles_to_move.push((next.0 + 1, next.1));
+                }
+                all_tiles.extend(&tiles_to_move);
+                while tiles_to_move.iter().any(|p| {
+                    matches!(terrain[step(m, *p)], TileD::BoxLeft)
+                        || matches!(terrain[step(m, *p)], TileD::BoxRight)
+                }) {
+                    let left: Vec<_> = tiles_to_move
+                        .iter()
+                        .copied()
+                        .filter(|t| matches!(terrain[step(m, *t)], TileD::BoxLeft))
+                        .collect();
+                    let right: Vec<_> = tiles_to_move
+                        .iter()
+                        .copied()
+                        .filter(|t| matches!(terrain[step(m, *t)], TileD::BoxRight))
+                        .collect();
+                    tiles_to_move.retain(|t| {
+                        matches!(terrain[step(m, *t)], TileD::Free)
+                            || matches!(terrain[step(m, *t)], TileD::Wall)
+                    });
+                    for t in left {
+                        let (x, y) = step(m, t);
+                        tiles_to_move.extend([(x, y), (x + 1, y)]);
+                    }
+                    for t in right {
+                        let (x, y) = step(m, t);
+                        tiles_to_move.extend([(x, y), (x - 1, y)]);
+                    }
+
+                    tiles_to_move = HashSet::<_, RandomState>::from_iter(tiles_to_move)
+                        .into_iter()
+                        .collect();
+                    all_tiles.extend(&tiles_to_move);
+                }
+                if tiles_to_move
+                    .iter()
+                    .all(|t| matches!(terrain[step(m, *t)], TileD::Free))
+                {
+                    *position = next;
+                    let mut tiles: Vec<_> = all_tiles.into_iter().collect();
+                    tiles.sort_unstable_by_key(|(x, y)| (*y, *x));
+                    if matches!(m, Dir::Down) {
+                        tiles.reverse();
+                    }
+                    for t in tiles {
+                        terrain.swap(t, step(m, t));
+                    }
+                }
+                trace!("{tiles_to_move:?}");
+            }
+        },
+    }
+}
+
+/// The robot going through its moves one at a time.
+#[derive(Clone)]
+struct Warehouse<T> {
+    terrain: Grid<T>,
+    position: Coord,
+    moves: Vec<Dir>,
+    /// Index of the next move in `moves`.
+    next: usize,
+    move_robot: fn(&mut Grid<T>, &mut Coord, Dir),
+}
+
+impl<T: Copy + Into<char>> Warehouse<T> {
+    /// The warehouse once the robot made every move.
+    fn run(mut self) -> Self {
+        while self.step() {}
+        self
+    }
+}
+
+impl<T: Copy + Into<char>> Simulation for Warehouse<T> {
+    fn step(&mut self) -> bool {
+        let Some(m) = self.moves.get(self.next) else {
+            return false;
+        };
+        (self.move_robot)(&mut self.terrain, &mut self.position, *m);
+        self.next += 1;
+        true
+    }
+
+    fn scene(&self) -> Scene {
+        Scene::new(&self.terrain, |t| (*t).into()).actor(self.position, '@', Color::Yellow)
+    }
+}
+
+fn warehouse(data: PuzzleData) -> Warehouse<Tile> {
+    Warehouse {
+        terrain: data.terrain,
+        position: data.position,
+        moves: data.moves,
+        next: 0,
+        move_robot: move_narrow,
+    }
+}
+
+fn wide_warehouse(data: PuzzleData) -> Warehouse<TileD> {
+    let width = data.terrain.width() * 2;
+    let tiles = data
+        .terrain
+        .cells()
+        .iter()
+        .flat_map(|t| match t {
+            Tile::Wall => [TileD::Wall; 2],
+            Tile::Free => [TileD::Free; 2],
+            Tile::Box => [TileD::BoxLeft, TileD::BoxRight],
+        })
+        .collect();
+    let terrain = Grid::from_cells(width, tiles);
+    Warehouse {
+        terrain,
+        position: (data.position.0 * 2, data.position.1),
+        moves: data.moves,
+        next: 0,
+        move_robot: move_wide,
+    }
+}
+
+fn part1(data: PuzzleData) -> u64 {
+    warehouse(data)
+        .run()
+        .terrain
+        .iter()
+        .filter(|t| matches!(t.1, Tile::Box))
+        .map(|((x, y), _)| y * 100 + x)
+        .sum::<usize>() as u64
+}
+
+fn part2(data: PuzzleData) -> u64 {
+    wide_warehouse(data)
+        .run()
+        .terrain
+        .iter()
+        .filter(|t| matches!(t.1, TileD::BoxLeft))
+        .map(|((x, y), _)| y * 100 + x)
+        .sum::<usize>() as u64
+}
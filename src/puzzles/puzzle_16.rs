@@ -0,0 +1,136 @@
+use std::{collections::HashSet, io::BufRead};
+
+use crate::{
+    parse,
+    random::{self, Rng},
+    render::{Color, Scene},
+    search, Answer, Coord, Dir, Grid, ParseError, Part, Solution,
+};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 16;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+    fn render(data: PuzzleData, part: Part) -> Option<Scene> {
+        Some(render(data, part))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    walls: Grid<bool>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+type Pos = ((usize, usize), Dir);
+
+impl PuzzleData {
+    fn next_paths(&self, &(pos, dir): &Pos) -> Vec<(Pos, u64)> {
+        [(1, dir), (1001, dir.turn_left()), (1001, dir.turn_right())]
+            .into_iter()
+            .filter_map(|(cost, d)| Some(((d.step(pos)?, d), cost)))
+            .filter(|p| self.walls.get(p.0 .0) == Some(&false))
+            .collect()
+    }
+
+    fn ends(&self) -> impl Iterator<Item = Pos> + '_ {
+        Dir::ALL.into_iter().map(|d| (self.end, d))
+    }
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let rows = parse::lines(lines).collect::<Result<Vec<_>, _>>()?;
+    let map = Grid::parse(
+        &rows,
+        |c| "#.SE ".contains(c).then_some(c),
+        "one of `#`, `.`, `S`, `E` or ` `",
+    )?;
+    let (Some(start), Some(end)) = (map.find(|c| *c == 'S'), map.find(|c| *c == 'E')) else {
+        Err(ParseError::eof(
+            rows.len() + 1,
+            "both a start `S` and an end `E`",
+        ))?
+    };
+    let walls = map.map(|c| *c == '#');
+    Ok(PuzzleData { walls, start, end })
+}
+
+/// A `size` by `size` maze from S bottom left to E top right, a tenth of its
+/// inner walls knocked down for paths to loop and tie.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5) | 1;
+    let mut walls = random::maze(rng, size, size);
+    for c in walls.coords().collect::<Vec<_>>() {
+        let inner = (1..(size - 1)).contains(&c.0) && (1..(size - 1)).contains(&c.1);
+        // Not the corners between passages
+        let between = c.0 % 2 == 1 || c.1 % 2 == 1;
+        if inner && between && walls[c] && rng.chance(0.1) {
+            walls[c] = false;
+        }
+    }
+    let mut map = walls.map(|w| if *w { '#' } else { '.' });
+    map[(1, size - 2)] = 'S';
+    map[(size - 2, 1)] = 'E';
+    format!("{map}\n")
+}
+
+fn part1(data: PuzzleData) -> u64 {
+    let search = search::dijkstra((data.start, Dir::Right), |p| data.next_paths(p));
+    data.ends()
+        .filter_map(|end| search.cost(&end))
+        .min()
+        .expect("end to be reachable")
+}
+
+/// Tiles on any of the best paths.
+fn best_seats(data: &PuzzleData) -> HashSet<Coord> {
+    let search = search::dijkstra_all((data.start, Dir::Right), |p| data.next_paths(p));
+    let min = data.ends().filter_map(|end| search.cost(&end)).min();
+    let best_ends = data.ends().filter(|end| search.cost(end) == min);
+    search
+        .on_optimal_paths(best_ends)
+        .into_iter()
+        .map(|p| p.0)
+        .collect()
+}
+
+fn part2(data: PuzzleData) -> u64 {
+    best_seats(&data).len() as u64
+}
+
+/// One of the best paths for part 1, all of them for part 2.
+fn render(data: PuzzleData, part: Part) -> Scene {
+    let tiles: Vec<_> = match part {
+        Part::One => {
+            let search = search::dijkstra((data.start, Dir::Right), |p| data.next_paths(p));
+            let (_, end) = data
+                .ends()
+                .filter_map(|end| Some((search.cost(&end)?, end)))
+                .min_by_key(|(cost, _)| *cost)
+                .expect("end to be reachable");
+            let path = search.path(&end).expect("end to be reachable");
+            path.into_iter().map(|p| p.0).collect()
+        }
+        Part::Two => best_seats(&data).into_iter().collect(),
+    };
+    Scene::new(&data.walls, |w| if *w { '#' } else { '.' })
+        .path(tiles, 'O', Color::Green)
+        .actor(data.start, 'S', Color::Yellow)
+        .actor(data.end, 'E', Color::Yellow)
+}
@@ -1,37 +1,43 @@
 #![allow(dead_code)]
-use std::{env, fs::File as FileFs, io::Read};
+use std::io::BufRead;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 3 {
-        eprintln!("Not enough arguments\nUSAGE: PART ./puzzle-17.exe FILE\n\tWhere PART is one of `1` or `2`");
-        return;
+use crate::{
+    cancel,
+    parse::{self, Line},
+    random::Rng,
+    trace, Answer, ParseError, Solution,
+};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 17;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(mut input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        let mut raw_data = String::new();
+        input
+            .read_to_string(&mut raw_data)
+            .map_err(|_| ParseError::new(1, 1, "", "valid UTF-8 text"))?;
+        parse_input(&raw_data)
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
     }
-    let mut raw_data = String::new();
-    let Ok(mut file) = FileFs::open(args[2].clone()) else {
-        eprintln!("Error reading `{}`", args[2]);
-        return;
-    };
-    file.read_to_string(&mut raw_data).unwrap();
-
-    let data = parse_input(&raw_data);
-    let res: PuzzleResult = match args[1].as_str() {
-        "1" => part1(data),
-        "2" => part2(data),
-        _ => {
-            eprint!("Arguments invalid\nUSAGE: PART ./puzzle-17.exe FILE\n\tWhere PART must be one of `1` or `2`");
-            return;
-        }
-    };
-    println!("Got result `{res}`!");
 }
 
 #[allow(non_camel_case_types)]
 type u3 = u8;
-type PuzzleResult = String;
 type RegType = u64;
 
-fn print_result(res: &Vec<u3>) -> String {
+fn print_result(res: &[u3]) -> String {
     let mut r = res.iter().fold(String::new(), |mut acc: String, d| {
         acc.push_str(&d.to_string());
         acc.push(',');
@@ -44,7 +50,7 @@ fn print_result(res: &Vec<u3>) -> String {
 }
 
 #[derive(Clone, Debug)]
-struct PuzzleData {
+pub struct PuzzleData {
     reg_a: RegType,
     reg_b: RegType,
     reg_c: RegType,
@@ -93,28 +99,61 @@ enum OpCode {
     Cdv = 7,
 }
 
-fn remove_text(input: &str) -> &str {
-    let colon = input.find(':').unwrap();
-    &input[(colon + 2)..]
+fn remove_text(line: &Line) -> Result<&str, ParseError> {
+    let colon = line.find(':')?;
+    Ok(line.text[(colon + 1)..].trim_start())
 }
-fn parse_input(data: &str) -> PuzzleData {
-    let mut lines = data.lines();
-
-    let reg_a = remove_text(lines.next().unwrap()).parse().unwrap();
-    let reg_b = remove_text(lines.next().unwrap()).parse().unwrap();
-    let reg_c = remove_text(lines.next().unwrap()).parse().unwrap();
-    lines.next().unwrap();
-    let prog = remove_text(lines.next().unwrap())
+fn parse_input(data: &str) -> Result<PuzzleData, ParseError> {
+    let lines: Vec<Line> = parse::str_lines(data).collect();
+    let line =
+        |i: usize, expected: &str| lines.get(i).ok_or_else(|| ParseError::eof(i + 1, expected));
+    let register = |i: usize| {
+        let l = line(i, "a register")?;
+        l.parse(remove_text(l)?, "a register value")
+    };
+
+    let reg_a = register(0)?;
+    let reg_b = register(1)?;
+    let reg_c = register(2)?;
+    let l = line(4, "a program")?;
+    let prog = remove_text(l)?
         .split(',')
-        .map(str::parse)
-        .map(Result::unwrap)
-        .collect::<Vec<u3>>();
+        .map(|op| match l.parse::<u3>(op, "a 3-bit number")? {
+            v @ 0..=7 => Ok(v),
+            _ => Err(l.error(op, "a 3-bit number")),
+        })
+        .collect::<Result<Vec<u3>, _>>()?;
 
-    PuzzleData {
+    Ok(PuzzleData {
         reg_a,
         reg_b,
         reg_c,
         prog,
+    })
+}
+
+/// A program like the puzzle's, printing A 3 bits at a time, A having `size`
+/// octal digits (up to 21). Its constants are drawn again until part 2 has
+/// an answer.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let digits = size.clamp(1, 21) as u32;
+    let a = rng.range(8usize.pow(digits - 1)..=(8usize.pow(digits) - 1));
+    loop {
+        // B = A % 8 ^ k1, C = A >> B, B ^= k2 ^ C, A >>= 3, out B, loop
+        let (k1, k2, operand) = (rng.below(8), rng.below(8), rng.below(8));
+        let mut prog = [2, 4, 1, k1, 7, 5, 1, k2, 4, operand, 0, 3, 5, 5, 3, 0];
+        if rng.chance(0.5) {
+            prog.swap(6, 8);
+            prog.swap(7, 9);
+        }
+        let prog: Vec<_> = prog.iter().map(usize::to_string).collect();
+        let text = format!(
+            "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            prog.join(",")
+        );
+        if part2(parse_input(&text).expect("a valid program")).is_some() {
+            return text;
+        }
     }
 }
 
@@ -220,18 +259,30 @@ impl Vm {
     }
 
     fn run(&mut self) {
-        while let Some(_op) = self.step() {}
+        let mut steps = 0u64;
+        while let Some(_op) = self.step() {
+            // Programs may never halt
+            steps += 1;
+            if steps.is_multiple_of(1024) {
+                cancel::check();
+            }
+        }
     }
 }
 
-fn part1(data: PuzzleData) -> PuzzleResult {
+fn part1(data: PuzzleData) -> Vec<u3> {
     let mut vm = Vm::new(&data);
+    let mut steps = 0u64;
     while let Some(_op) = vm.step() {
-        println!("{_op:?}");
+        trace!("{_op:?}");
+        steps += 1;
+        if steps.is_multiple_of(1024) {
+            cancel::check();
+        }
     }
-    print_result(&vm.out)
+    vm.out
 }
-fn part2(data: PuzzleData) -> PuzzleResult {
+fn part2(data: PuzzleData) -> Option<RegType> {
     let goal = data.prog.clone();
     let mut past_as = vec![0];
     for i in 0..goal.len() {
@@ -250,17 +301,17 @@ fn part2(data: PuzzleData) -> PuzzleResult {
                 {
                     past_as.push((past_a << 3) + a);
                 }
-                println!("a: {} | out: {}", a | (past_a << 3), print_result(&vm.out));
+                trace!("a: {} | out: {}", a | (past_a << 3), print_result(&vm.out));
             }
         }
     }
 
-    format!("{:?}", past_as.iter().min())
+    past_as.iter().min().copied()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     const EX_1: &str = "Register A: 729
 Register B: 0
@@ -281,15 +332,17 @@ Program: 0,3,5,4,3,0";
 
     #[test]
     fn test_1() {
-        let data_1 = parse_input(EX_1);
-        assert_eq!(part1(data_1), "4,6,3,5,6,3,5,2,1,0");
-        println!();
-        let data_2 = parse_input(EX_2);
-        assert_eq!(part1(data_2), "4,2,5,6,7,7,7,7,3,1,0");
+        let data_1 = parse_input(EX_1).unwrap();
+        assert_eq!(print_result(&part1(data_1)), "4,6,3,5,6,3,5,2,1,0");
+        let data_2 = parse_input(EX_2).unwrap();
+        assert_eq!(print_result(&part1(data_2)), "4,2,5,6,7,7,7,7,3,1,0");
     }
     #[test]
     fn test_2() {
-        let data_3 = parse_input(EX_3);
-        assert_eq!(part2(data_3), "Some(117440)");
+        let data_3 = parse_input(EX_3).unwrap();
+        assert_eq!(part2(data_3), Some(117440));
+        // No value of A makes this one print itself
+        let data_1 = parse_input(EX_1).unwrap();
+        assert_eq!(Puzzle::part2(data_1).to_string(), "none");
     }
 }
@@ -0,0 +1,304 @@
+#![allow(dead_code)]
+use std::{collections::HashSet, io::BufRead};
+
+use crate::{
+    animate::{Frames, Replay, Simulation},
+    debug, params, parse,
+    random::Rng,
+    render::{Color, Scene},
+    search, Answer, Coord, Grid, Params, ParseError, Part, Solution,
+};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 18;
+    type Data = PuzzleData;
+    type Params = PuzzleParams;
+
+    fn parse_input(mut input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        let mut raw_data = String::new();
+        input
+            .read_to_string(&mut raw_data)
+            .map_err(|_| ParseError::new(1, 1, "", "valid UTF-8 text"))?;
+        parse_input(&raw_data)
+    }
+    fn set_params(data: &mut PuzzleData, params: &PuzzleParams) {
+        data.params = params.clone();
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(&data).1.into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+    fn render(data: PuzzleData, part: Part) -> Option<Scene> {
+        Some(render(data, part))
+    }
+    fn animate(mut data: PuzzleData, part: Part) -> Option<Box<dyn Frames>> {
+        let until = (part == Part::One).then_some(data.params.fallen);
+        data.params.fallen = 0;
+        Some(Box::new(Replay::new(Falling { data, until })))
+    }
+}
+
+type PuzzleResult = Option<usize>;
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    params: PuzzleParams,
+    bytes: Vec<(usize, usize)>,
+}
+
+/// The example is `width=7 fallen=12`.
+#[derive(Clone, Debug)]
+pub struct PuzzleParams {
+    /// Of the square memory space.
+    width: usize,
+    /// Bytes fallen before part 1 looks for a path.
+    fallen: usize,
+}
+
+impl Default for PuzzleParams {
+    fn default() -> Self {
+        PuzzleParams {
+            width: 71,
+            fallen: 1024,
+        }
+    }
+}
+
+impl Params for PuzzleParams {
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("width", self.width.to_string()),
+            ("fallen", self.fallen.to_string()),
+        ]
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "width" => self.width = params::value(name, value)?,
+            "fallen" => self.fallen = params::value(name, value)?,
+            name => Err(format!("Unknown parameter `{name}`"))?,
+        }
+        Ok(())
+    }
+}
+
+fn parse_input(data: &str) -> Result<PuzzleData, ParseError> {
+    let bytes = parse::str_lines(data)
+        .map(|line| {
+            let l = line.text.as_str();
+            let comma = line.find(',')?;
+            Ok((
+                line.parse(&l[..comma], "a coordinate")?,
+                line.parse(&l[(comma + 1)..], "a coordinate")?,
+            ))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(PuzzleData {
+        params: PuzzleParams::default(),
+        bytes,
+    })
+}
+
+/// Bytes falling on the default memory space, the first ones as many as
+/// part 1 waits for and leaving a way out. `size` of them, or up to the first
+/// closing the way out if later.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let PuzzleParams { width, fallen } = PuzzleParams::default();
+    let exit = (width - 1, width - 1);
+    let escapes = |bytes: &[Coord]| {
+        let mut corrupted = Grid::new(width, width, false);
+        for c in bytes {
+            corrupted[*c] = true;
+        }
+        search::bfs((0, 0), |c| {
+            corrupted
+                .neighbours4(*c)
+                .filter(|n| !corrupted[*n])
+                .collect::<Vec<_>>()
+        })
+        .cost(&exit)
+        .is_some()
+    };
+    let mut bytes: Vec<_> = (0..width * width)
+        .map(|i| (i % width, i / width))
+        .filter(|c| *c != (0, 0) && *c != exit)
+        .collect();
+    // A fifth of the space rarely closes the way out already
+    rng.shuffle(&mut bytes);
+    while !escapes(&bytes[..fallen]) {
+        rng.shuffle(&mut bytes);
+    }
+    // The first byte closing the way out, by bisection
+    let (mut open, mut closed) = (fallen, bytes.len());
+    while closed - open > 1 {
+        let mid = (open + closed) / 2;
+        if escapes(&bytes[..mid]) {
+            open = mid;
+        } else {
+            closed = mid;
+        }
+    }
+    bytes.truncate(size.max(closed));
+    bytes.iter().map(|(x, y)| format!("{x},{y}\n")).collect()
+}
+
+impl PuzzleData {
+    fn fallen_bytes(&self) -> &[(usize, usize)] {
+        &self.bytes.as_slice()[..self.params.fallen]
+    }
+
+    /// The memory space, `true` where a byte has fallen.
+    fn corrupted(&self) -> Grid<bool> {
+        let width = self.params.width;
+        let mut corrupted = Grid::new(width, width, false);
+        for p in self.fallen_bytes() {
+            corrupted[*p] = true;
+        }
+        corrupted
+    }
+}
+
+fn part1(data: &PuzzleData) -> (HashSet<(usize, usize)>, Option<usize>) {
+    let start = (0usize, 0usize);
+    let width = data.params.width;
+    let end = (width - 1, width - 1);
+
+    let corrupted = data.corrupted();
+    let search = search::bfs(start, |p| {
+        corrupted
+            .neighbours4(*p)
+            .filter(|n| !corrupted[*n])
+            .collect::<Vec<_>>()
+    });
+    let path: HashSet<_> = search.path(&end).into_iter().flatten().collect();
+    debug!("{:?}", search.cost(&end));
+    (path, search.cost(&end))
+}
+/// The first byte cutting the exit off, `None` if the exit stays open.
+fn part2(mut data: PuzzleData) -> Option<(usize, usize)> {
+    while let (p, Some(_)) = part1(&data) {
+        // Bytes off the path leave it open
+        loop {
+            let byte = data.bytes.get(data.params.fallen)?;
+            data.params.fallen += 1;
+            if p.contains(byte) {
+                break;
+            }
+        }
+    }
+    data.fallen_bytes().last().copied()
+}
+
+/// The bytes fallen so far, the last one in red, and the shortest path
+/// left.
+fn scene(data: &PuzzleData) -> Scene {
+    let width = data.params.width;
+    let (path, _) = part1(data);
+    let mut scene =
+        Scene::new(&data.corrupted(), |c| if *c { '#' } else { '.' }).path(path, 'O', Color::Green);
+    if let Some(last) = data.fallen_bytes().last() {
+        scene = scene.actor(*last, '#', Color::Red);
+    }
+    scene
+        .actor((0, 0), 'S', Color::Yellow)
+        .actor((width - 1, width - 1), 'E', Color::Yellow)
+}
+
+/// Stops at the byte cutting the exit off for part 2.
+fn render(mut data: PuzzleData, part: Part) -> Scene {
+    if part == Part::Two {
+        if let Some(blocking) = part2(data.clone()) {
+            data.params.fallen = data
+                .bytes
+                .iter()
+                .position(|b| *b == blocking)
+                .expect("blocking byte to have fallen")
+                + 1;
+        }
+    }
+    scene(&data)
+}
+
+/// The bytes falling one at a time, `until` that many have for part 1, or
+/// until the exit is cut off for part 2.
+#[derive(Clone)]
+struct Falling {
+    data: PuzzleData,
+    until: Option<usize>,
+}
+
+impl Simulation for Falling {
+    fn step(&mut self) -> bool {
+        let fallen = self.data.params.fallen;
+        let over = match self.until {
+            Some(until) => fallen >= until,
+            None => part1(&self.data).1.is_none(),
+        };
+        if over || fallen == self.data.bytes.len() {
+            return false;
+        }
+        self.data.params.fallen += 1;
+        true
+    }
+
+    fn scene(&self) -> Scene {
+        scene(&self.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    mod examples {
+        pub const EX_1: &str = "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+    }
+
+    #[test]
+    fn test_1() {
+        let mut data = parse_input(examples::EX_1).unwrap();
+        data.params.width = 7;
+        data.params.fallen = 12;
+        assert!(matches!(part1(&data), (_, Some(22))));
+    }
+    #[test]
+    fn test_2() {
+        let mut data = parse_input(examples::EX_1).unwrap();
+        data.params.width = 7;
+        data.params.fallen = 12;
+        assert_eq!(part2(data.clone()), Some((6, 1)));
+        // Too few bytes to cut the exit off
+        data.bytes.truncate(data.params.fallen);
+        assert_eq!(part2(data), None);
+    }
+}
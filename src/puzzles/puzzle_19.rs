@@ -0,0 +1,190 @@
+#![allow(dead_code)]
+use std::{collections::HashMap, io::BufRead};
+
+use crate::{debug, parallel, parse, random::Rng, trace, Answer, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 19;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(mut input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        let mut raw_data = String::new();
+        input
+            .read_to_string(&mut raw_data)
+            .map_err(|_| ParseError::new(1, 1, "", "valid UTF-8 text"))?;
+        parse_input(&raw_data)
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+type PuzzleResult = usize;
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    patterns: Vec<String>,
+    designs: Vec<String>,
+    combinations: HashMap<String, usize>,
+}
+
+fn parse_input(data: &str) -> Result<PuzzleData, ParseError> {
+    let mut lines = parse::str_lines(data);
+    let Some(line) = lines.next() else {
+        Err(ParseError::eof(1, "towel patterns"))?
+    };
+    let patterns: Vec<String> = line
+        .text
+        .split(',')
+        .map(|p| match p.trim() {
+            "" => Err(line.error(p, "a towel pattern")),
+            p => Ok(p.to_owned()),
+        })
+        .collect::<Result<_, _>>()?;
+    lines.next();
+    let designs = lines.map(|l| l.text).collect();
+    let combinations = HashMap::new();
+    let mut d = PuzzleData {
+        combinations,
+        patterns: patterns.clone(),
+        designs,
+    };
+
+    for p in patterns {
+        d.combinations(&p, 0);
+    }
+    Ok(d)
+}
+
+/// `size` towel patterns of up to 8 stripes, then `size` designs, half made
+/// of patterns and half of random stripes. One color has no towel of its
+/// own, leaving some designs impossible.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+    fn stripes(rng: &mut Rng, len: usize) -> String {
+        (0..len).map(|_| *rng.pick(&COLORS)).collect()
+    }
+    let alone = rng.pick(&COLORS).to_string();
+    let mut patterns: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let len = rng.range(1..=8);
+            stripes(rng, len)
+        })
+        .collect();
+    patterns.retain(|p| *p != alone);
+    patterns.sort_unstable();
+    patterns.dedup();
+    rng.shuffle(&mut patterns);
+    let designs: Vec<String> = (0..size)
+        .map(|_| {
+            if rng.chance(0.5) {
+                let count = rng.range(3..=10);
+                (0..count).map(|_| rng.pick(&patterns).as_str()).collect()
+            } else {
+                let len = rng.range(20..=60);
+                stripes(rng, len)
+            }
+        })
+        .collect();
+    format!("{}\n\n{}\n", patterns.join(", "), designs.join("\n"))
+}
+
+impl PuzzleData {
+    fn is_possible(&mut self, design: &str) -> bool {
+        self.combinations(design, 0) != 0
+    }
+
+    fn combinations(&mut self, design: &str, depth: usize) -> usize {
+        if let Some(c) = self.combinations.get(design) {
+            trace!("{:depth$}FOUND {design}={c}", "");
+            return *c;
+        };
+        let mut c = 0;
+        if self.patterns.iter().any(|p| p == design) {
+            c += 1;
+        } else {
+            if design.is_empty() {
+                return 1;
+            }
+            if design.len() == 1 {
+                self.combinations.insert(design.to_string(), 0);
+                return 0;
+            }
+        }
+        let pats: Vec<String> = self
+            .patterns
+            .iter()
+            .filter(|p| p.len() < design.len() && design.starts_with(p.as_str()))
+            .cloned()
+            .collect();
+        for p in pats {
+            trace!("{:depth$} PAT {p} IN {design}", "");
+            c += self.combinations(&design[p.len()..], depth + 1);
+        }
+        self.combinations.insert(design.to_string(), c);
+        c
+    }
+}
+
+fn part1(data: PuzzleData) -> PuzzleResult {
+    parallel::map_with(
+        &data.designs,
+        || data.clone(),
+        |data, d| data.is_possible(d),
+    )
+    .into_iter()
+    .filter(|p| *p)
+    .count()
+}
+fn part2(data: PuzzleData) -> PuzzleResult {
+    // Each thread fills its own cache
+    parallel::map_with(
+        &data.designs,
+        || data.clone(),
+        |data, d| {
+            let h = data.combinations(d, 0);
+            debug!("{d} => {h}");
+            h
+        },
+    )
+    .into_iter()
+    .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    mod examples {
+        pub const EX_1: &str = "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb";
+    }
+
+    #[test]
+    fn test_1() {
+        let data = parse_input(examples::EX_1).unwrap();
+
+        assert_eq!(part1(data), 6);
+    }
+    #[test]
+    fn test_2() {
+        let data = parse_input(examples::EX_1).unwrap();
+        assert!(matches!(part2(data), 16));
+    }
+}
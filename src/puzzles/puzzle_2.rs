@@ -0,0 +1,107 @@
+use std::io::BufRead;
+
+use crate::{parse, random::Rng, Answer, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 2;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    reports: Vec<Vec<u64>>,
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let reports = parse::lines(lines)
+        .map(|line| {
+            let line = line?;
+            line.text
+                .split_ascii_whitespace()
+                .map(|level| line.parse::<u64>(level, "a level"))
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(PuzzleData { reports })
+}
+
+/// `size` reports, safe ones with up to two levels changed.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = Vec::new();
+    for _ in 0..size {
+        let len = rng.range(5..=8);
+        let step = if rng.chance(0.5) { 1 } else { -1 };
+        let mut level = rng.signed(25..=70);
+        let mut levels = Vec::new();
+        for _ in 0..len {
+            levels.push(level);
+            level += step * rng.signed(1..=3);
+        }
+        for _ in 0..rng.range(0..=2) {
+            let i = rng.below(len);
+            levels[i] = rng.signed(1..=99);
+        }
+        let levels: Vec<_> = levels.iter().map(i64::to_string).collect();
+        lines.push(levels.join(" ") + "\n");
+    }
+    lines.concat()
+}
+
+fn check_levels<T: Iterator<Item = u64>>(levels: T) -> bool {
+    let mut prev = None;
+    let mut dir = None;
+    for level in levels {
+        if let Some(p) = prev {
+            if !(1..=3).contains(&level.abs_diff(p)) {
+                return false;
+            }
+            if dir.is_none() {
+                dir = Some(level < p);
+            } else if dir.is_some_and(|d| d != (level < p)) {
+                return false;
+            }
+        }
+        prev = Some(level);
+    }
+    true
+}
+fn part1(data: PuzzleData) -> u64 {
+    data.reports
+        .into_iter()
+        .map(|levels| check_levels(levels.into_iter()))
+        .filter(|b| *b)
+        .count() as u64
+}
+
+fn part2(data: PuzzleData) -> u64 {
+    data.reports
+        .into_iter()
+        .map(|levels| {
+            if check_levels(levels.iter().copied()) {
+                return true;
+            }
+            let vars = vec![levels.clone(); levels.len()];
+            vars.into_iter().enumerate().any(|(id, mut var)| {
+                var.remove(id);
+                check_levels(var.into_iter())
+            })
+        })
+        .filter(|b| *b)
+        .count() as u64
+}
@@ -0,0 +1,249 @@
+#![allow(dead_code)]
+
+use std::{collections::HashMap, io::BufRead};
+
+use crate::{
+    params, parse,
+    random::{self, Rng},
+    render::{Color, Scene},
+    search, Answer, Coord, Grid, Params, ParseError, Part, Solution,
+};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 20;
+    type Data = PuzzleData;
+    type Params = PuzzleParams;
+
+    fn parse_input(mut input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        let mut raw_data = String::new();
+        input
+            .read_to_string(&mut raw_data)
+            .map_err(|_| ParseError::new(1, 1, "", "valid UTF-8 text"))?;
+        parse_input(&raw_data)
+    }
+    fn set_params(data: &mut PuzzleData, params: &PuzzleParams) {
+        data.params = params.clone();
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+    fn render(data: PuzzleData, part: Part) -> Option<Scene> {
+        Some(render(data, part))
+    }
+}
+
+type PuzzleResult = usize;
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    walls: Grid<bool>,
+    start: Coord,
+    end: Coord,
+    params: PuzzleParams,
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleParams {
+    /// Picoseconds a cheat must save to be counted.
+    threshold: usize,
+}
+
+impl Default for PuzzleParams {
+    fn default() -> Self {
+        PuzzleParams { threshold: 100 }
+    }
+}
+
+impl Params for PuzzleParams {
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![("threshold", self.threshold.to_string())]
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "threshold" => self.threshold = params::value(name, value)?,
+            name => Err(format!("Unknown parameter `{name}`"))?,
+        }
+        Ok(())
+    }
+}
+
+fn parse_input(data: &str) -> Result<PuzzleData, ParseError> {
+    let rows: Vec<_> = parse::str_lines(data).collect();
+    let map = Grid::parse(
+        &rows,
+        |c| "#.SE".contains(c).then_some(c),
+        "one of `#`, `.`, `S` or `E`",
+    )?;
+    let (Some(start), Some(end)) = (map.find(|c| *c == 'S'), map.find(|c| *c == 'E')) else {
+        Err(ParseError::eof(
+            rows.len() + 1,
+            "both a start `S` and an end `E`",
+        ))?
+    };
+
+    Ok(PuzzleData {
+        walls: map.map(|c| *c == '#'),
+        start,
+        end,
+        params: PuzzleParams::default(),
+    })
+}
+
+/// A single track winding through a `size` by `size` maze, from S top left
+/// to the cell furthest from it.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5) | 1;
+    let walls = random::maze(rng, size, size);
+    let search = search::bfs((1, 1), |c| {
+        walls
+            .neighbours4(*c)
+            .filter(|n| !walls[*n])
+            .collect::<Vec<_>>()
+    });
+    // Ties broken by position, the search visiting cells in any order
+    let (end, _) = search
+        .reached()
+        .max_by_key(|(c, steps)| (*steps, **c))
+        .expect("the start to be reached");
+    let mut map = Grid::new(size, size, '#');
+    for c in search.path(end).expect("a path to what was reached") {
+        map[c] = '.';
+    }
+    map[(1, 1)] = 'S';
+    map[*end] = 'E';
+    format!("{map}\n")
+}
+
+impl PuzzleData {
+    fn compute_path(&self) -> Vec<Coord> {
+        let mut res = Vec::new();
+        let mut curr = self.start;
+        while curr != self.end {
+            res.push(curr);
+            curr = self
+                .walls
+                .neighbours4(curr)
+                .find(|n| !self.walls[*n] && !res.contains(n))
+                .expect("track to lead to the end");
+        }
+        res.push(self.end);
+        res
+    }
+
+    fn compute_path_map(&self) -> HashMap<Coord, usize> {
+        self.compute_path()
+            .into_iter()
+            .enumerate()
+            .map(|(a, b)| (b, a))
+            .collect()
+    }
+    fn cheats(&self, cheat_time: usize) -> Vec<(usize, Coord, Coord)> {
+        if cheat_time < 2 {
+            return vec![];
+        }
+        let mut cheats = Vec::new();
+        let path_map = self.compute_path_map();
+        let path = self.compute_path();
+        for (i, p) in path.iter().enumerate() {
+            for other in &path[(i + 1)..] {
+                if dist(p, other) <= cheat_time && dist(p, other) > 1 {
+                    let time_saved = path_map[other] - path_map[p] - dist(p, other);
+                    if time_saved > 0 {
+                        cheats.push((time_saved, *p, *other));
+                    }
+                }
+            }
+        }
+        cheats
+    }
+}
+
+fn dist(c1: &Coord, c2: &Coord) -> usize {
+    c1.0.abs_diff(c2.0) + c1.1.abs_diff(c2.1)
+}
+/// The track, with where the cheats saving at least `threshold` start and
+/// end highlighted.
+fn render(data: PuzzleData, part: Part) -> Scene {
+    let cheat_time = match part {
+        Part::One => 2,
+        Part::Two => 20,
+    };
+    let ends: Vec<_> = data
+        .cheats(cheat_time)
+        .into_iter()
+        .filter(|c| c.0 >= data.params.threshold)
+        .flat_map(|(_, from, to)| [from, to])
+        .collect();
+    Scene::new(&data.walls, |w| if *w { '#' } else { '.' })
+        .path(data.compute_path(), 'O', Color::Green)
+        .highlight(ends, Color::Red)
+        .actor(data.start, 'S', Color::Yellow)
+        .actor(data.end, 'E', Color::Yellow)
+}
+
+fn part1(data: PuzzleData) -> PuzzleResult {
+    let threshold = data.params.threshold;
+    data.cheats(2).iter().filter(|c| c.0 >= threshold).count()
+}
+fn part2(data: PuzzleData) -> PuzzleResult {
+    let threshold = data.params.threshold;
+    data.cheats(20).iter().filter(|c| c.0 >= threshold).count()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    mod examples {
+        pub const EX_1: &str = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+    }
+
+    #[test]
+    fn test_path() {
+        let data = parse_input(examples::EX_1).unwrap();
+
+        assert_eq!(data.compute_path().len(), 85);
+    }
+    #[test]
+    fn test_cheats() {
+        let data = parse_input(examples::EX_1).unwrap();
+        let cheats = data.cheats(2);
+        assert_eq!(cheats.len(), 44);
+        assert!(cheats.iter().find(|c| c.0 == 64).is_some());
+        assert!(cheats.iter().find(|c| c.0 > 64).is_none());
+        assert!(cheats.iter().find(|c| c.0 < 2).is_none());
+        let cheats: Vec<_> = data.cheats(20).into_iter().filter(|c| c.0 >= 50).collect();
+        assert_eq!(cheats.len(), 285);
+        assert!(cheats.iter().find(|c| c.0 == 76).is_some());
+        assert!(cheats.iter().find(|c| c.0 > 76).is_none());
+    }
+
+    #[test]
+    fn test_2() {
+        let mut data = parse_input(examples::EX_1).unwrap();
+        data.params.threshold = 50;
+        assert_eq!(part2(data), 285);
+    }
+}
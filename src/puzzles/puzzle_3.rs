@@ -1,33 +1,72 @@
-use std::{
-    env,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::io::BufRead;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 3 {
-        eprintln!("Not enough arguments\nUSAGE: PART ./puzzle-3.exe FILE\n\tWhere PART is one of `1` or `2`");
-        return;
+use crate::{parse, random::Rng, trace, Answer, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 3;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
     }
-    let Ok(lines) = File::open(args[2].clone()).map(|f| BufReader::new(f).lines()) else {
-        eprintln!("Error reading `{}`", args[2]);
-        return;
-    };
-    let sum: u64 = match args[1].as_str() {
-        "1" => part1(lines),
-        "2" => part2(lines),
-        _ => {
-            eprint!("Arguments invalid\nUSAGE: PART ./puzzle-3.exe FILE\n\tWhere PART must be one of `1` or `2`");
-            return;
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    lines: Vec<String>,
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let lines = parse::lines(lines)
+        .map(|line| Ok(line?.text))
+        .collect::<Result<_, _>>()?;
+    Ok(PuzzleData { lines })
+}
+
+/// `size` instructions, valid or corrupted, amid noise and 60 to a line.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const NOISE: [&str; 10] = [
+        "!", "@", "#", "^", "[", "]", " ", "what()", "from()", "why()",
+    ];
+    let mut out = String::new();
+    for i in 0..size {
+        let (a, b) = (rng.range(1..=999), rng.range(1..=999));
+        let instruction = match rng.below(10) {
+            0 => "do()".to_owned(),
+            1 => "don't()".to_owned(),
+            2 => format!("mul[{a},{b}]"),
+            3 => format!("mul({a}*"),
+            4 => format!("mul ( {a} , {b} )"),
+            5 => format!("mul({},{b})", 1000 + a),
+            _ => format!("mul({a},{b})"),
+        };
+        out.push_str(&instruction);
+        for _ in 0..rng.range(0..=3) {
+            let noise: &&str = rng.pick(&NOISE);
+            out.push_str(noise);
+        }
+        if i % 60 == 59 || i == size - 1 {
+            out.push('\n');
         }
-    };
-    println!("Got result `{sum}`!");
+    }
+    out
 }
 
-fn part1(lines: std::io::Lines<BufReader<File>>) -> u64 {
-    lines
-        .map(Result::unwrap)
+fn part1(data: PuzzleData) -> u64 {
+    data.lines
+        .into_iter()
         .map(|l| {
             let mut s = l.as_str();
             let mut sum = 0;
@@ -55,7 +94,7 @@ fn part1(lines: std::io::Lines<BufReader<File>>) -> u64 {
                     s = &s[par..];
                     continue;
                 };
-                println!("mul({left}*{right})");
+                trace!("mul({left}*{right})");
                 s = &s[(par + 1)..];
                 sum += right * left;
             }
@@ -64,10 +103,10 @@ fn part1(lines: std::io::Lines<BufReader<File>>) -> u64 {
         .sum()
 }
 
-fn part2(lines: std::io::Lines<BufReader<File>>) -> u64 {
+fn part2(data: PuzzleData) -> u64 {
     let mut enabled = true;
-    lines
-        .map(Result::unwrap)
+    data.lines
+        .into_iter()
         .map(|l| {
             let mut s = l.as_str();
             let mut sum = 0;
@@ -80,7 +119,7 @@ fn part2(lines: std::io::Lines<BufReader<File>>) -> u64 {
                     _ => (),
                 }
 
-                println!("{enable_tags:?} - {enabled}");
+                trace!("{enable_tags:?} - {enabled}");
                 s = &s[(idx + 4)..];
                 if !enabled {
                     continue;
@@ -107,7 +146,7 @@ fn part2(lines: std::io::Lines<BufReader<File>>) -> u64 {
                     s = &s[par..];
                     continue;
                 };
-                println!("mul({left}*{right})");
+                trace!("mul({left}*{right})");
                 s = &s[(par + 1)..];
                 sum += right * left;
             }
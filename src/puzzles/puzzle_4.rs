@@ -0,0 +1,71 @@
+use std::io::BufRead;
+
+use crate::{parse, random::Rng, Answer, Grid, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 4;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    letters: Grid<char>,
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let rows = parse::lines(lines).collect::<Result<Vec<_>, _>>()?;
+    let letters = Grid::parse(&rows, Some, "a letter")?;
+    Ok(PuzzleData { letters })
+}
+
+/// A `size` by `size` grid of the letters of XMAS.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let letters = (0..size * size)
+        .map(|_| *rng.pick(&['X', 'M', 'A', 'S']))
+        .collect();
+    format!("{}\n", Grid::from_cells(size, letters))
+}
+
+fn part1(data: PuzzleData) -> u64 {
+    let grid = data.letters;
+    let lines = grid.rows().map(|row| row.iter().collect::<String>());
+    let columns = grid.columns().map(Iterator::collect::<String>);
+    let diag_d = grid.diagonals().map(Iterator::collect::<String>);
+    let diag_u = grid.anti_diagonals().map(Iterator::collect::<String>);
+    let all = lines.chain(diag_d).chain(diag_u).chain(columns);
+    all.map(|s| s.matches("XMAS").count() + s.matches("SAMX").count())
+        .sum::<usize>() as u64
+}
+
+fn part2(data: PuzzleData) -> u64 {
+    let grid = data.letters;
+    let is_mas = |a: Option<&char>, b: Option<&char>| {
+        matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')))
+    };
+    grid.iter()
+        .filter(|((x, y), c)| {
+            if **c != 'A' || *x == 0 || *y == 0 {
+                return false;
+            }
+            let (x, y) = (*x, *y);
+            is_mas(grid.get((x - 1, y - 1)), grid.get((x + 1, y + 1)))
+                && is_mas(grid.get((x - 1, y + 1)), grid.get((x + 1, y - 1)))
+        })
+        .count() as u64
+}
@@ -0,0 +1,219 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+};
+
+use crate::{debug, parse, random::Rng, trace, Answer, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 5;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    orderings: HashMap<u64, HashSet<u64>>,
+    updates: Vec<Vec<u64>>,
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let mut orderings: HashMap<u64, HashSet<u64>> = HashMap::new();
+    let mut updates = Vec::new();
+    for line in parse::lines(lines) {
+        let line = line?;
+        let l = line.text.as_str();
+        if l.is_empty() {
+            continue;
+        }
+        match l.find('|') {
+            Some(i) => {
+                let before = line.parse(&l[..i], "a page number")?;
+                let after = line.parse(&l[(i + 1)..], "a page number")?;
+                orderings.entry(before).or_default().insert(after);
+            }
+            None => updates.push(
+                l.split(',')
+                    .map(|n| line.parse::<u64>(n, "a page number"))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        }
+    }
+    Ok(PuzzleData { orderings, updates })
+}
+
+/// Rules ordering every two of `size` pages (at most 90) like a hidden
+/// order would, then 4 × `size` updates, half of them in order.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut order: Vec<usize> = (10..100).collect();
+    rng.shuffle(&mut order);
+    order.truncate(size.clamp(3, 90));
+    let mut rules = Vec::new();
+    for (i, before) in order.iter().enumerate() {
+        for after in &order[(i + 1)..] {
+            rules.push(format!("{before}|{after}\n"));
+        }
+    }
+    rng.shuffle(&mut rules);
+    let mut updates = Vec::new();
+    for _ in 0..4 * size {
+        let mut pages = order.clone();
+        rng.shuffle(&mut pages);
+        // An odd number of pages, for the update to have a middle
+        let len = 2 * rng.range(2..=order.len().min(23).div_ceil(2)) - 1;
+        pages.truncate(len);
+        if rng.chance(0.5) {
+            pages.sort_by_key(|p| order.iter().position(|o| o == p));
+        }
+        let pages: Vec<_> = pages.iter().map(usize::to_string).collect();
+        updates.push(pages.join(",") + "\n");
+    }
+    format!("{}\n{}", rules.concat(), updates.concat())
+}
+
+fn part1(data: PuzzleData) -> u64 {
+    let PuzzleData { orderings, updates } = data;
+    let mut sum = 0;
+    for update in updates {
+        let mut previous = HashSet::new();
+        let mut ok = true;
+        for page in &update {
+            trace!("{previous:?}");
+            if orderings
+                .get(page)
+                .map(|s| s.intersection(&previous).count())
+                .is_none_or(|c| c == 0)
+            {
+                previous.insert(*page);
+            } else {
+                trace!("break");
+                ok = false;
+                break;
+            }
+        }
+        if ok {
+            let middle = update[update.len() / 2];
+            debug!("{middle}");
+            sum += middle;
+        }
+    }
+    sum
+}
+
+fn swap_error(update: &mut [u64], orderings: &HashMap<u64, HashSet<u64>>) -> bool {
+    let mut all_previous = vec![HashSet::new()];
+    let mut error = (0, 0);
+    let mut swapped = false;
+    for (from, page) in update.iter().enumerate() {
+        let previous = all_previous.last_mut().unwrap();
+        if orderings
+            .get(page)
+            .map(|s| s.intersection(previous).count())
+            .is_none_or(|c| c == 0)
+        {
+            let mut c = previous.clone();
+            c.insert(*page);
+            all_previous.push(c);
+        } else {
+            // The most pages it can follow, counted back from the end
+            match all_previous.iter().rev().position(|p| {
+                orderings
+                    .get(page)
+                    .map(|s| s.intersection(p).count())
+                    .is_none_or(|c| c == 0)
+            }) {
+                Some(back) => {
+                    error = (from, from - back);
+                }
+                None => error = (from, 0),
+            }
+
+            swapped = true;
+            break;
+        }
+    }
+    if swapped {
+        update.swap(error.0, error.1);
+    }
+    swapped
+}
+
+fn part2(data: PuzzleData) -> u64 {
+    let PuzzleData { orderings, updates } = data;
+    let mut sum = 0;
+    for mut update in updates {
+        if !swap_error(&mut update, &orderings) {
+            continue;
+        }
+        while swap_error(&mut update, &orderings) {}
+        let middle = update[update.len() / 2];
+        debug!("{middle}");
+        sum += middle;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::*;
+    use crate::property;
+
+    /// The update sorted by the rules, naively.
+    fn reference_order(update: &[u64], orderings: &HashMap<u64, HashSet<u64>>) -> Vec<u64> {
+        let before = |a: &u64, b: &u64| orderings.get(a).is_some_and(|after| after.contains(b));
+        let mut sorted = update.to_vec();
+        sorted.sort_by(|a, b| match (before(a, b), before(b, a)) {
+            (true, _) => Ordering::Less,
+            (_, true) => Ordering::Greater,
+            _ => Ordering::Equal,
+        });
+        sorted
+    }
+
+    #[test]
+    fn test_swap_error() {
+        // Rules ordering pages 0 to 9 by number, like any hidden order would
+        let orderings: HashMap<u64, HashSet<u64>> =
+            (0..10).map(|p| (p, ((p + 1)..10).collect())).collect();
+        property::check(
+            500,
+            |rng| (0..rng.range(1..=7)).map(|_| rng.below(10) as u8).collect(),
+            |pages: &Vec<u8>| {
+                let mut update = Vec::new();
+                for p in pages.iter().map(|p| u64::from(*p)) {
+                    if !update.contains(&p) {
+                        update.push(p);
+                    }
+                }
+                let expected = reference_order(&update, &orderings);
+                // Fixing a page at a time, more swaps mean a loop
+                for _ in 0..=update.len().pow(2) {
+                    if !swap_error(&mut update, &orderings) {
+                        return match update == expected {
+                            true => Ok(()),
+                            false => Err(format!("fixed to {update:?}, not {expected:?}")),
+                        };
+                    }
+                }
+                Err(format!("still swapping at {update:?}"))
+            },
+        );
+    }
+}
@@ -1,111 +1,106 @@
-use std::{
-    collections::HashSet,
-    env,
-    fs::File,
-    io::{BufRead, BufReader},
-    iter,
+use std::{collections::HashSet, io::BufRead, iter};
+
+use crate::{
+    animate::{Frames, Replay, Simulation},
+    cancel, debug, parallel, parse,
+    random::Rng,
+    render::{Color, Scene},
+    Answer, Dir, Grid, ParseError, Part, Solution,
 };
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 3 {
-        eprintln!("Not enough arguments\nUSAGE: PART ./puzzle-6.exe FILE\n\tWhere PART is one of `1` or `2`");
-        return;
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 6;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+    /// Part 2 tries thousands of patrols, so only part 1's is replayed.
+    fn animate(data: PuzzleData, part: Part) -> Option<Box<dyn Frames>> {
+        (part == Part::One).then(|| Box::new(Replay::new(Patrol::new(data))) as _)
     }
-    let Ok(lines) = File::open(args[2].clone()).map(|f| BufReader::new(f).lines()) else {
-        eprintln!("Error reading `{}`", args[2]);
-        return;
-    };
-    let Ok(data) = parse_input(lines) else {
-        eprintln!("Error reading `{}`", args[2]);
-        return;
-    };
-    let sum: u64 = match args[1].as_str() {
-        "1" => part1(data),
-        "2" => part2(data),
-        _ => {
-            eprint!("Arguments invalid\nUSAGE: PART ./puzzle-6.exe FILE\n\tWhere PART must be one of `1` or `2`");
-            return;
-        }
-    };
-    println!("Got result `{sum}`!");
 }
 
 #[derive(Clone)]
-struct PuzzleData {
+pub struct PuzzleData {
     start: (usize, usize),
     lines: Vec<Vec<usize>>,
     cols: Vec<Vec<usize>>,
     width: usize,
+    height: usize,
 }
 
-fn parse_input(lines: std::io::Lines<BufReader<File>>) -> Result<PuzzleData, String> {
-    let mut lines = lines.peekable();
-    let Some(Ok(width)) = lines.peek() else {
-        Err("Could nor parse input file")?
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let rows = parse::lines(lines).collect::<Result<Vec<_>, _>>()?;
+    let map = Grid::parse(
+        &rows,
+        |c| ".#^".contains(c).then_some(c),
+        "one of `.`, `#` or `^`",
+    )?;
+    let Some(guard) = map.find(|c| *c == '^') else {
+        Err(ParseError::eof(rows.len() + 1, "a guard `^` on the map"))?
     };
-    let width = width.len();
-    let (guard, obstacles) = lines
-        .map(Result::unwrap)
-        .enumerate()
-        .map(|(line_idx, line)| {
-            (
-                line_idx,
-                line.find('^'),
-                line.match_indices('#').map(|e| e.0).collect::<Vec<_>>(),
-            )
-        })
-        .fold((None, Vec::new()), |(mut g, mut o), (l_idx, g_o, os)| {
-            g = g.or(g_o.map(|x| (x, l_idx)));
-            o.extend(os.iter().map(|e| (*e, l_idx)));
-            (g, o)
-        });
-    let lines: Vec<Vec<usize>> = obstacles
-        .iter()
-        .fold(vec![Vec::new(); width], |mut acc, o| {
-            acc[o.1].push(o.0);
-            acc
-        })
-        .into_iter()
-        .map(|mut v| {
-            v.sort_unstable();
-            v
-        })
-        .collect::<Vec<_>>();
-    let cols = obstacles
-        .iter()
-        .fold(vec![Vec::new(); width], |mut acc, o| {
-            acc[o.0].push(o.1);
-            acc
-        })
-        .into_iter()
-        .map(|mut v| {
-            v.sort_unstable();
-            v
-        })
-        .collect::<Vec<_>>();
+    let mut lines = vec![Vec::new(); map.height()];
+    let mut cols = vec![Vec::new(); map.width()];
+    // Row by row, so both lists come out sorted
+    for ((x, y), _) in map.iter().filter(|(_, c)| **c == '#') {
+        lines[y].push(x);
+        cols[x].push(y);
+    }
 
     Ok(PuzzleData {
-        start: guard.unwrap(),
+        start: guard,
         lines,
         cols,
-        width,
+        width: map.width(),
+        height: map.height(),
     })
 }
-#[derive(Debug, Clone, Copy, PartialEq)]
-enum Dir {
-    Up,
-    Down,
-    Left,
-    Right,
+
+/// A `size` by `size` lab, a tenth of it obstructed, and the guard facing up.
+/// Labs are drawn again until the guard leaves, as in the puzzle.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    loop {
+        let cells = (0..size * size)
+            .map(|_| if rng.chance(0.1) { '#' } else { '.' })
+            .collect();
+        let mut map = Grid::from_cells(size, cells);
+        map[(rng.below(size), rng.below(size))] = '^';
+        let text = format!("{map}\n");
+        let data = parse_input(text.as_bytes().lines()).expect("a valid lab");
+        let mut guard = Guard {
+            pos: data.start,
+            dir: Dir::Up,
+        };
+        // Longer than that, the guard walks in a loop
+        for _ in 0..=4 * size * size {
+            match next_step(&guard, &data) {
+                StepKind::Forward(next) | StepKind::Rotate(next) => guard = next,
+                StepKind::Oob => return text,
+            }
+        }
+    }
 }
 
 fn part1(data: PuzzleData) -> u64 {
-    let mut path = vec![0u8; data.width * data.width];
-    let xy_to_idx = |(x, y)| x + data.width * y;
+    let mut path = Grid::new(data.width, data.height, false);
     let mut guard = data.start;
     let mut dir = Dir::Up;
+    // A guard walking in a loop never leaves
     loop {
+        cancel::check();
         match dir {
             Dir::Up => {
                 let obstacle = data.cols[guard.0].binary_search(&guard.1).unwrap_err();
@@ -117,30 +112,28 @@ fn part1(data: PuzzleData) -> u64 {
                 };
                 iter::repeat(guard.0)
                     .zip(obstacle_y..=guard.1)
-                    .map(xy_to_idx)
-                    .for_each(|idx| path[idx] = 1);
+                    .for_each(|xy| path[xy] = true);
                 if obstacle_y == 0 {
                     break;
                 }
-                dir = Dir::Right;
+                dir = dir.turn_right();
                 guard.1 = obstacle_y;
             }
             Dir::Down => {
                 let obstacle = data.cols[guard.0].binary_search(&guard.1).unwrap_err();
 
                 let obstacle_y = if obstacle == data.cols[guard.0].len() {
-                    data.width - 1
+                    data.height - 1
                 } else {
                     data.cols[guard.0][obstacle] - 1
                 };
                 iter::repeat(guard.0)
                     .zip(guard.1..=obstacle_y)
-                    .map(xy_to_idx)
-                    .for_each(|idx| path[idx] = 1);
-                if obstacle_y == data.width - 1 {
+                    .for_each(|xy| path[xy] = true);
+                if obstacle_y == data.height - 1 {
                     break;
                 }
-                dir = Dir::Left;
+                dir = dir.turn_right();
                 guard.1 = obstacle_y;
             }
             Dir::Left => {
@@ -153,12 +146,11 @@ fn part1(data: PuzzleData) -> u64 {
                 };
                 (obstacle_x..=guard.0)
                     .zip(iter::repeat(guard.1))
-                    .map(xy_to_idx)
-                    .for_each(|idx| path[idx] = 1);
+                    .for_each(|xy| path[xy] = true);
                 if obstacle_x == 0 {
                     break;
                 }
-                dir = Dir::Up;
+                dir = dir.turn_right();
                 guard.0 = obstacle_x;
             }
             Dir::Right => {
@@ -171,19 +163,18 @@ fn part1(data: PuzzleData) -> u64 {
                 };
                 (guard.0..=obstacle_x)
                     .zip(iter::repeat(guard.1))
-                    .map(xy_to_idx)
-                    .for_each(|idx| path[idx] = 1);
+                    .for_each(|xy| path[xy] = true);
                 if obstacle_x == data.width - 1 {
                     break;
                 }
-                dir = Dir::Down;
+                dir = dir.turn_right();
                 guard.0 = obstacle_x;
             }
         }
     }
-    path.into_iter().filter(|e| *e == 1).count() as u64
+    path.cells().iter().filter(|e| **e).count() as u64
 }
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 struct Guard {
     pos: (usize, usize),
     dir: Dir,
@@ -203,7 +194,7 @@ fn next_step(guard: &Guard, data: &PuzzleData) -> StepKind {
             {
                 StepKind::Rotate(Guard {
                     pos: guard.pos,
-                    dir: Dir::Right,
+                    dir: guard.dir.turn_right(),
                 })
             } else if guard.pos.1 > 0 {
                 StepKind::Forward(Guard {
@@ -221,9 +212,9 @@ fn next_step(guard: &Guard, data: &PuzzleData) -> StepKind {
             {
                 StepKind::Rotate(Guard {
                     pos: guard.pos,
-                    dir: Dir::Left,
+                    dir: guard.dir.turn_right(),
                 })
-            } else if guard.pos.1 < data.width - 1 {
+            } else if guard.pos.1 < data.height - 1 {
                 StepKind::Forward(Guard {
                     pos: (guard.pos.0, guard.pos.1 + 1),
                     dir: Dir::Down,
@@ -239,7 +230,7 @@ fn next_step(guard: &Guard, data: &PuzzleData) -> StepKind {
             {
                 StepKind::Rotate(Guard {
                     pos: guard.pos,
-                    dir: Dir::Up,
+                    dir: guard.dir.turn_right(),
                 })
             } else if guard.pos.0 > 0 {
                 StepKind::Forward(Guard {
@@ -257,7 +248,7 @@ fn next_step(guard: &Guard, data: &PuzzleData) -> StepKind {
             {
                 StepKind::Rotate(Guard {
                     pos: guard.pos,
-                    dir: Dir::Down,
+                    dir: guard.dir.turn_right(),
                 })
             } else if guard.pos.0 < data.width - 1 {
                 StepKind::Forward(Guard {
@@ -272,8 +263,6 @@ fn next_step(guard: &Guard, data: &PuzzleData) -> StepKind {
 }
 
 fn part2(data: PuzzleData) -> u64 {
-    const LOOP_SIZE: usize = 100_000;
-
     let mut guard = Guard {
         pos: data.start,
         dir: Dir::Up,
@@ -291,32 +280,84 @@ fn part2(data: PuzzleData) -> u64 {
     }
 
     possible_obstacles.remove(&data.start);
-    let mut obstacle_count = 0u64;
-    for ob in &possible_obstacles {
+    let possible_obstacles: Vec<_> = possible_obstacles.into_iter().collect();
+    let loops = parallel::map(&possible_obstacles, |ob| {
         let mut n_data = data.clone();
         n_data.cols[ob.0].push(ob.1);
         n_data.lines[ob.1].push(ob.0);
         n_data.cols[ob.0].sort_unstable();
         n_data.lines[ob.1].sort_unstable();
 
+        cancel::check();
         let mut guard = Guard {
             pos: data.start,
             dir: Dir::Up,
         };
-        obstacle_count += 1;
-        for _ in 0..LOOP_SIZE {
+        // Turning the same way at the same place again, the guard is looping
+        let mut turns = HashSet::new();
+        loop {
             match next_step(&guard, &n_data) {
-                StepKind::Forward(new_guard) => {
+                StepKind::Forward(new_guard) => guard = new_guard,
+                StepKind::Rotate(new_guard) => {
+                    if !turns.insert(new_guard.clone()) {
+                        return true;
+                    }
                     guard = new_guard;
                 }
-                StepKind::Rotate(new_guard) => guard = new_guard,
-                StepKind::Oob => {
-                    obstacle_count -= 1;
-                    break;
-                }
+                StepKind::Oob => return false,
             };
         }
+    });
+    debug!("{} possible obstacles", possible_obstacles.len());
+    loops.into_iter().filter(|l| *l).count() as u64
+}
+
+/// The guard walking one step at a time.
+#[derive(Clone)]
+struct Patrol {
+    data: PuzzleData,
+    guard: Guard,
+    visited: Grid<bool>,
+}
+
+impl Patrol {
+    fn new(data: PuzzleData) -> Self {
+        let mut visited = Grid::new(data.width, data.height, false);
+        visited[data.start] = true;
+        Patrol {
+            guard: Guard {
+                pos: data.start,
+                dir: Dir::Up,
+            },
+            data,
+            visited,
+        }
+    }
+}
+
+impl Simulation for Patrol {
+    fn step(&mut self) -> bool {
+        match next_step(&self.guard, &self.data) {
+            StepKind::Forward(guard) => {
+                self.visited[guard.pos] = true;
+                self.guard = guard;
+            }
+            StepKind::Rotate(guard) => self.guard = guard,
+            StepKind::Oob => return false,
+        }
+        true
+    }
+
+    fn scene(&self) -> Scene {
+        let mut map = Grid::new(self.data.width, self.data.height, '.');
+        for (y, xs) in self.data.lines.iter().enumerate() {
+            for x in xs {
+                map[(*x, y)] = '#';
+            }
+        }
+        let visited = self.visited.iter().filter(|(_, v)| **v).map(|(c, _)| c);
+        Scene::new(&map, |c| *c)
+            .path(visited, 'X', Color::Green)
+            .actor(self.guard.pos, self.guard.dir.arrow(), Color::Yellow)
     }
-    dbg!(possible_obstacles.len());
-    obstacle_count
 }
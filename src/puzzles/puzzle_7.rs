@@ -0,0 +1,132 @@
+use std::io::BufRead;
+
+use crate::{parallel, parse, random::Rng, trace, Answer, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 7;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    eqs: Vec<(u64, Vec<u64>)>,
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let eqs = parse::lines(lines)
+        .map(|line| {
+            let line = line?;
+            let l = line.text.as_str();
+            let col = line.find(':')?;
+            let operands: Vec<u64> = l[(col + 1)..]
+                .split_ascii_whitespace()
+                .map(|n| line.parse(n, "an operand"))
+                .collect::<Result<_, _>>()?;
+            if operands.len() < 2 {
+                Err(line.error(line.end(), "at least two operands"))?
+            }
+            Ok((line.parse(&l[..col], "a test value")?, operands))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(PuzzleData { eqs })
+}
+
+/// `size` equations of 2 to 12 operands, two thirds of them solvable.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = Vec::new();
+    for _ in 0..size {
+        let count = rng.range(2..=12);
+        // 15 digits in all at most, like the puzzle's, for any result and
+        // their sum to fit
+        let mut budget = 15;
+        let operands: Vec<u64> = (0..count)
+            .map(|i| {
+                let digits = rng.range(1..=3).min(budget - (count - 1 - i));
+                budget -= digits;
+                let low = 10usize.pow(digits as u32 - 1);
+                rng.range(low..=(10 * low - 1)) as u64
+            })
+            .collect();
+        let mut value = operands[0];
+        for n in &operands[1..] {
+            value = match rng.below(3) {
+                0 => value + n,
+                1 => value * n,
+                _ => format!("{value}{n}").parse().expect("at most 15 digits"),
+            };
+        }
+        if rng.chance(1.0 / 3.0) {
+            value += rng.range(1..=9) as u64;
+        }
+        let operands: Vec<_> = operands.iter().map(u64::to_string).collect();
+        lines.push(format!("{value}: {}\n", operands.join(" ")));
+    }
+    lines.concat()
+}
+
+fn part1(data: PuzzleData) -> u64 {
+    parallel::map(&data.eqs, solvable_2).into_iter().sum()
+}
+
+/// The test value of `eq` if adding and multiplying can make it, else 0.
+fn solvable_2(eq: &(u64, Vec<u64>)) -> u64 {
+    let p = 2 << ((eq.1.len() - 1) - 1);
+    for i in 0..p {
+        let mut sum = eq.1[0];
+        for j in 0..(eq.1.len() - 1) {
+            if (i >> j) % 2 == 0 {
+                sum += eq.1[j + 1];
+            } else {
+                sum *= eq.1[j + 1];
+            }
+        }
+        if sum == eq.0 {
+            trace!("{eq:?}");
+            return eq.0;
+        }
+    }
+    0
+}
+
+fn part2(data: PuzzleData) -> u64 {
+    parallel::map(&data.eqs, solvable_3).into_iter().sum()
+}
+
+/// Like [`solvable_2`], concatenating too.
+fn solvable_3(eq: &(u64, Vec<u64>)) -> u64 {
+    let p = 3_usize.pow(eq.1.len() as u32 - 1);
+    for i in 0..p {
+        let mut sum = eq.1[0];
+        let mut r_i = i;
+        for j in 0..(eq.1.len() - 1) {
+            match r_i % 3 {
+                0 => sum += eq.1[j + 1],
+                1 => sum *= eq.1[j + 1],
+                2 => sum = format!("{sum}{}", eq.1[j + 1]).parse().unwrap(),
+                _ => unreachable!(),
+            }
+            r_i /= 3;
+        }
+        if sum == eq.0 {
+            trace!("{eq:?}");
+            return eq.0;
+        }
+    }
+    0
+}
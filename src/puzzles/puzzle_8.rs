@@ -0,0 +1,114 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+};
+
+use gcd::Gcd;
+
+use crate::{parse, random::Rng, trace, Answer, Grid, ParseError, Solution, Vec2D};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 8;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    antennas: HashMap<char, Vec<(usize, usize)>>,
+    map: Grid<char>,
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let rows = parse::lines(lines).collect::<Result<Vec<_>, _>>()?;
+    let map = Grid::parse(
+        &rows,
+        |c| (c == '.' || c.is_ascii_alphanumeric()).then_some(c),
+        "an antenna or `.`",
+    )?;
+    let mut antennas = HashMap::new();
+    for (xy, c) in map.iter().filter(|v| v.1.ne(&'.')) {
+        antennas
+            .entry(*c)
+            .and_modify(|v: &mut Vec<(usize, usize)>| v.push(xy))
+            .or_insert(vec![xy]);
+    }
+    Ok(PuzzleData { antennas, map })
+}
+
+/// A `size` by `size` map with 2 to 4 antennas of each of `size / 4`
+/// frequencies.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let mut map = Grid::new(size, size, '.');
+    for f in &FREQUENCIES[..(size / 4).clamp(1, FREQUENCIES.len())] {
+        for _ in 0..rng.range(2..=4) {
+            map[(rng.below(size), rng.below(size))] = *f as char;
+        }
+    }
+    format!("{map}\n")
+}
+
+fn part1(data: PuzzleData) -> u64 {
+    let mut antinodes = HashSet::new();
+    for ants in data.antennas.values() {
+        for pair in ants
+            .iter()
+            .flat_map(|l| ants.iter().map(move |r| (l, r)))
+            .filter(|(x, y)| *x != *y)
+        {
+            let (a, b) = (Vec2D::from(*pair.0), Vec2D::from(*pair.1));
+            let diff = a - b;
+            let antis = [data.map.coord(b - diff), data.map.coord(a + diff)];
+            antinodes.extend(antis.into_iter().flatten());
+        }
+    }
+
+    antinodes.len() as u64
+}
+
+fn part2(data: PuzzleData) -> u64 {
+    let mut antinodes = HashSet::new();
+    for ants in data.antennas.values() {
+        for pair in ants
+            .iter()
+            .flat_map(|l| ants.iter().map(move |r| (l, r)))
+            .filter(|(x, y)| *x != *y)
+        {
+            let (a, b) = (Vec2D::from(*pair.0), Vec2D::from(*pair.1));
+            let diff = a - b;
+            let diff_gcd = diff.x.unsigned_abs().gcd(diff.y.unsigned_abs()) as isize;
+            let reduced = diff / diff_gcd;
+            trace!("{diff:?} - {diff_gcd:?} - {reduced:?}");
+            let diff = reduced;
+            let mut antis = Vec::new();
+            let mut p = a;
+            while let Some(c) = data.map.coord(p) {
+                antis.push(c);
+                p += diff;
+            }
+            let mut p = b;
+            while let Some(c) = data.map.coord(p) {
+                antis.push(c);
+                p -= diff;
+            }
+            antinodes.extend(antis);
+        }
+    }
+    antinodes.len() as u64
+}
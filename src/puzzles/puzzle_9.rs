@@ -0,0 +1,231 @@
+use std::io::BufRead;
+
+use crate::{debug, parse, random::Rng, Answer, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 9;
+    type Data = PuzzleData;
+    type Params = ();
+
+    fn parse_input(input: impl BufRead) -> Result<PuzzleData, ParseError> {
+        parse_input(input.lines())
+    }
+    fn part1(data: PuzzleData) -> Answer {
+        part1(data).into()
+    }
+    fn part2(data: PuzzleData) -> Answer {
+        part2(data).into()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Block {
+    id: Option<usize>,
+    size: usize,
+    pos: usize,
+}
+#[derive(Clone, Debug)]
+pub struct PuzzleData {
+    files: Vec<Block>,
+}
+
+fn parse_input(lines: std::io::Lines<impl BufRead>) -> Result<PuzzleData, ParseError> {
+    let Some(line) = parse::lines(lines).next() else {
+        Err(ParseError::eof(1, "a disk map"))?
+    };
+    let line = line?;
+    let mut files = Vec::new();
+    let mut id = Some(0);
+    let mut pos = 0;
+    for (i, v) in line.text.chars().enumerate() {
+        let Some(size) = v.to_digit(10) else {
+            Err(line.error_at(i, "a digit"))?
+        };
+        let (is_file, size) = (i % 2 == 0, size as usize);
+        if is_file {
+            files.push(Block { id, size, pos });
+            id = Some(id.unwrap() + 1);
+        } else {
+            files.push(Block {
+                id: None,
+                size,
+                pos,
+            });
+        }
+        pos += size;
+    }
+    Ok(PuzzleData { files })
+}
+
+/// A disk map of `size` digits, one more if even so that it ends on a file.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let digits: String = (0..(size | 1))
+        .map(|i| {
+            // Files take a block at least
+            let min = if i % 2 == 0 { 1 } else { 0 };
+            char::from_digit(rng.range(min..=9) as u32, 10).expect("a digit")
+        })
+        .collect();
+    digits + "\n"
+}
+/// The block holding `index`, the last starting there or before: empty free
+/// blocks share their position with the next file.
+fn get_block(index: usize, data: &PuzzleData) -> &Block {
+    &data.files[data.files.partition_point(|v| v.pos <= index) - 1]
+}
+fn part1(data: PuzzleData) -> u64 {
+    let mut sum = 0;
+    let mut lpos = 0;
+    let rpos = data.files.last().unwrap();
+    let mut rpos = rpos.pos + rpos.size - 1;
+    while rpos > lpos {
+        let block = get_block(lpos, &data);
+        if let Some(id) = block.id {
+            sum += lpos * id;
+            lpos += 1;
+        } else {
+            let rblock = get_block(rpos, &data);
+            if block == rblock {
+                break;
+            }
+            if let Some(id) = rblock.id {
+                sum += lpos * id;
+            } else {
+                rpos = rblock.pos - 1;
+                let rgblock = get_block(rpos, &data);
+                sum += lpos * rgblock.id.unwrap();
+            }
+            lpos += 1;
+            rpos -= 1;
+        }
+    }
+    // Unless both ends just passed each other, one block is left between them
+    let last_block = get_block(lpos, &data);
+    if let (true, Some(id)) = (lpos == rpos, last_block.id) {
+        sum += lpos * id;
+    }
+    sum as u64
+}
+
+fn part2(data: PuzzleData) -> u64 {
+    let mut frees = data
+        .files
+        .iter()
+        .filter(|b| b.id.is_none())
+        .cloned()
+        .collect::<Vec<_>>();
+    let mut files = Vec::new();
+
+    let get_file = |id: usize| &data.files[id * 2];
+    let mut file_id = data.files.last().unwrap().id.unwrap() + 1;
+    while file_id > 0 {
+        file_id -= 1;
+        let file = get_file(file_id);
+        let Some(free_space) = frees
+            .iter_mut()
+            .find(|f| f.size >= file.size && f.pos < file.pos)
+        else {
+            files.push(file.clone());
+            continue;
+        };
+        let mut n_file = file.clone();
+        n_file.pos = free_space.pos;
+        files.push(n_file);
+        free_space.pos += file.size;
+        free_space.size -= file.size;
+    }
+    files.sort_by_key(|f| f.pos);
+    debug!("{files:?}");
+    let mut sum = 0;
+    for f in files {
+        for i in f.pos..(f.pos + f.size) {
+            sum += i * f.id.unwrap();
+        }
+    }
+    sum as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property;
+
+    /// The disk block by block, `None` where free.
+    fn blocks(map: &str) -> Vec<Option<usize>> {
+        let mut blocks = Vec::new();
+        for (i, c) in map.chars().enumerate() {
+            let size = c.to_digit(10).unwrap() as usize;
+            let id = (i % 2 == 0).then_some(i / 2);
+            blocks.extend(std::iter::repeat_n(id, size));
+        }
+        blocks
+    }
+
+    fn checksum(blocks: &[Option<usize>]) -> u64 {
+        let ids = blocks.iter().enumerate();
+        ids.map(|(pos, id)| (pos * id.unwrap_or(0)) as u64).sum()
+    }
+
+    /// Part 1, moving the last file block to the first free one until done.
+    fn reference_part1(map: &str) -> u64 {
+        let mut blocks = blocks(map);
+        while let Some(free) = blocks.iter().position(Option::is_none) {
+            let last = blocks.iter().rposition(Option::is_some).unwrap();
+            if last < free {
+                break;
+            }
+            blocks.swap(free, last);
+        }
+        checksum(&blocks)
+    }
+
+    /// Part 2, moving whole files to the leftmost free span they fit in.
+    fn reference_part2(map: &str) -> u64 {
+        let mut blocks = blocks(map);
+        let files = map.len().div_ceil(2);
+        for id in (0..files).rev() {
+            let start = blocks.iter().position(|b| *b == Some(id)).unwrap();
+            let size = blocks.iter().filter(|b| **b == Some(id)).count();
+            let fits = (0..start)
+                .find(|&s| s + size <= start && blocks[s..s + size].iter().all(Option::is_none));
+            if let Some(s) = fits {
+                for i in 0..size {
+                    blocks.swap(s + i, start + i);
+                }
+            }
+        }
+        checksum(&blocks)
+    }
+
+    #[test]
+    fn test_checksums() {
+        // Files and the free space after them, then the last file
+        property::check(
+            300,
+            |rng| {
+                let pairs =
+                    (0..rng.range(0..=8)).map(|_| (rng.below(10) as u8, rng.below(10) as u8));
+                (pairs.collect(), rng.below(9) as u8)
+            },
+            |(pairs, last): &(Vec<(u8, u8)>, u8)| {
+                let mut map = String::new();
+                for (file, free) in pairs {
+                    map.push(char::from(b'1' + file % 9));
+                    map.push(char::from(b'0' + free % 10));
+                }
+                map.push(char::from(b'1' + last % 9));
+                let data = parse_input(map.as_bytes().lines()).unwrap();
+                let expected = (reference_part1(&map), reference_part2(&map));
+                match (part1(data.clone()), part2(data)) {
+                    answers if answers == expected => Ok(()),
+                    answers => Err(format!("{map}: {answers:?}, not {expected:?}")),
+                }
+            },
+        );
+    }
+}
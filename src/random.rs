@@ -0,0 +1,113 @@
+//! Seeded random numbers for generating puzzle inputs, a seed always giving
+//! the same input.
+
+use std::ops::RangeInclusive;
+
+use crate::{Dir, Grid};
+
+/// SplitMix64: small, fast and plenty random for test inputs. Ranges are
+/// reduced by modulo, their slight bias not mattering here.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Below `n`, which must be positive.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        range.start() + self.below(range.end() - range.start() + 1)
+    }
+
+    pub fn signed(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = range.end().abs_diff(*range.start()) + 1;
+        range.start().wrapping_add((self.next_u64() % span) as i64)
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    /// One of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Walls of a maze with exactly one path between any two cells, the cells
+/// being those at odd coordinates and the passages between them. Even sizes
+/// leave a wall two cells thick on the right or bottom.
+pub fn maze(rng: &mut Rng, width: usize, height: usize) -> Grid<bool> {
+    let mut walls = Grid::new(width, height, true);
+    if width < 3 || height < 3 {
+        return walls;
+    }
+    // Depth-first, which gives long corridors
+    let mut stack = vec![(1, 1)];
+    walls[(1, 1)] = false;
+    while let Some(&cell) = stack.last() {
+        let mut dirs = Dir::ALL;
+        rng.shuffle(&mut dirs);
+        let next = dirs.into_iter().find_map(|d| {
+            let passage = d.step(cell)?;
+            let next = d.step(passage)?;
+            (next.0 < width - 1 && next.1 < height - 1 && walls[next]).then_some((passage, next))
+        });
+        match next {
+            Some((passage, next)) => {
+                walls[passage] = false;
+                walls[next] = false;
+                stack.push(next);
+            }
+            None => _ = stack.pop(),
+        }
+    }
+    walls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(42);
+        let draws: Vec<_> = (0..100).map(|_| rng.range(3..=5)).collect();
+        assert!(draws.iter().all(|n| (3..=5).contains(n)));
+        assert!((3..=5).all(|n| draws.contains(&n)));
+        let mut again = Rng::new(42);
+        assert_eq!(
+            draws,
+            (0..100).map(|_| again.range(3..=5)).collect::<Vec<_>>()
+        );
+        assert!((0..100).all(|_| (-2..=2).contains(&rng.signed(-2..=2))));
+
+        let walls = maze(&mut rng, 7, 5);
+        let open = walls.iter().filter(|(_, w)| !**w).count();
+        // 6 cells, linked by 5 passages
+        assert_eq!(open, 11);
+        assert!(walls.row(0).iter().all(|w| *w));
+    }
+}
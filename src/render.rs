@@ -0,0 +1,166 @@
+//! Drawing of map puzzles in the terminal, with overlays on top of the map.
+
+use std::{
+    fmt::{self, Display, Write},
+    io::{self, IsTerminal},
+};
+
+use crate::{Coord, Grid};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    /// Offset of the color in the ANSI foreground (30) and background (40)
+    /// codes.
+    fn ansi(self) -> u8 {
+        match self {
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Style {
+    pub(crate) fg: Option<Color>,
+    pub(crate) bg: Option<Color>,
+    bold: bool,
+}
+
+impl Style {
+    fn write_ansi(&self, out: &mut String) {
+        out.push_str("\x1b[0");
+        if self.bold {
+            out.push_str(";1");
+        }
+        if let Some(fg) = self.fg {
+            write!(out, ";3{}", fg.ansi()).unwrap();
+        }
+        if let Some(bg) = self.bg {
+            write!(out, ";4{}", bg.ansi()).unwrap();
+        }
+        out.push('m');
+    }
+}
+
+/// A map drawn one char per cell, with overlays drawn over it in the order
+/// they are added. Colors only show with [`Scene::draw`] asked for ANSI.
+#[derive(Clone, Debug)]
+pub struct Scene {
+    cells: Grid<(char, Style)>,
+}
+
+impl Scene {
+    /// Draws each cell of `grid` as `glyph` of it.
+    pub fn new<T>(grid: &Grid<T>, mut glyph: impl FnMut(&T) -> char) -> Self {
+        Scene {
+            cells: grid.map(|c| (glyph(c), Style::default())),
+        }
+    }
+
+    /// Colors the background of `cells`, keeping what is drawn on them.
+    pub fn highlight(mut self, cells: impl IntoIterator<Item = Coord>, color: Color) -> Self {
+        for c in cells {
+            if let Some((_, style)) = self.cells.get_mut(c) {
+                style.bg = Some(color);
+            }
+        }
+        self
+    }
+
+    /// Draws `glyph` over `cells`, like the tiles of a path.
+    pub fn path(
+        mut self,
+        cells: impl IntoIterator<Item = Coord>,
+        glyph: char,
+        color: Color,
+    ) -> Self {
+        for c in cells {
+            if let Some((g, style)) = self.cells.get_mut(c) {
+                *g = glyph;
+                style.fg = Some(color);
+            }
+        }
+        self
+    }
+
+    /// Draws `glyph` in bold at `at`, like a robot or the end of a maze.
+    pub fn actor(mut self, at: Coord, glyph: char, color: Color) -> Self {
+        if let Some((g, style)) = self.cells.get_mut(at) {
+            *g = glyph;
+            style.fg = Some(color);
+            style.bold = true;
+        }
+        self
+    }
+
+    /// The scene as lines of text, colored with ANSI escape codes if `ansi`.
+    pub fn draw(&self, ansi: bool) -> String {
+        let mut out = String::new();
+        for (y, row) in self.cells.rows().enumerate() {
+            if y > 0 {
+                out.push('\n');
+            }
+            let mut current = Style::default();
+            for (glyph, style) in row {
+                if ansi && *style != current {
+                    style.write_ansi(&mut out);
+                    current = *style;
+                }
+                out.push(*glyph);
+            }
+            if current != Style::default() {
+                out.push_str("\x1b[0m");
+            }
+        }
+        out
+    }
+
+    /// Each cell drawn, with its style.
+    pub(crate) fn cells(&self) -> &Grid<(char, Style)> {
+        &self.cells
+    }
+
+    /// Prints the scene on stdout, in colors if it is a terminal.
+    pub fn print(&self) {
+        println!("{}", self.draw(io::stdout().is_terminal()));
+    }
+}
+
+impl Display for Scene {
+    /// Without colors.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.draw(false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draw() {
+        let grid = Grid::from_cells(3, vec![true, false, false, false, false, true]);
+        let scene = Scene::new(&grid, |w| if *w { '#' } else { '.' })
+            .path([(1, 0), (1, 1)], 'O', Color::Green)
+            .highlight([(1, 1), (5, 5)], Color::Red)
+            .actor((0, 1), '@', Color::Yellow);
+        assert_eq!(scene.to_string(), "#O.\n@O#");
+        assert_eq!(
+            scene.draw(true),
+            "#\x1b[0;32mO\x1b[0m.\n\x1b[0;1;33m@\x1b[0;32;41mO\x1b[0m#"
+        );
+    }
+}
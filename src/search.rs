@@ -0,0 +1,241 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Costs and predecessors of every state reached from a start state.
+#[derive(Clone, Debug)]
+pub struct Search<S, C> {
+    start: S,
+    costs: HashMap<S, C>,
+    parents: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new(start: S, zero: C) -> Self {
+        Search {
+            costs: HashMap::from([(start.clone(), zero)]),
+            parents: HashMap::new(),
+            start,
+        }
+    }
+
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    /// Lowest cost from the start to `state`, if it was reached.
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// Every reached state with its lowest cost.
+    pub fn reached(&self) -> impl Iterator<Item = (&S, C)> {
+        self.costs.iter().map(|(s, c)| (s, *c))
+    }
+
+    /// States leading to `state` on optimal paths: only the first one found,
+    /// unless the search kept them all.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.parents.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One optimal path from the start to `to`, both included.
+    pub fn path(&self, to: &S) -> Option<Vec<S>> {
+        self.costs.get(to)?;
+        let mut path = vec![to.clone()];
+        while let Some(prev) = self.predecessors(&path[path.len() - 1]).first() {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every state on any recorded optimal path to one of `targets`.
+    pub fn on_optimal_paths(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut to_visit: Vec<S> = targets
+            .into_iter()
+            .filter(|t| self.costs.contains_key(t))
+            .collect();
+        while let Some(state) = to_visit.pop() {
+            if seen.insert(state.clone()) {
+                to_visit.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        seen
+    }
+}
+
+/// Breadth-first search, the cost being the number of steps.
+pub fn bfs<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut to_visit = VecDeque::from([(start, 0)]);
+    while let Some((state, steps)) = to_visit.pop_front() {
+        for next in neighbours(&state) {
+            if !search.costs.contains_key(&next) {
+                search.costs.insert(next.clone(), steps + 1);
+                search.parents.insert(next.clone(), vec![state.clone()]);
+                to_visit.push_back((next, steps + 1));
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm, `neighbours` giving each next state with the cost
+/// of the move, which must not be negative.
+pub fn dijkstra<S, C, I>(start: S, neighbours: impl FnMut(&S) -> I) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(start, neighbours, |_| C::default(), |_| false, false).0
+}
+
+/// Like [`dijkstra`], keeping every predecessor on any optimal path.
+pub fn dijkstra_all<S, C, I>(start: S, neighbours: impl FnMut(&S) -> I) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(start, neighbours, |_| C::default(), |_| false, true).0
+}
+
+/// A* search up to the first state matching `goal`, returning its cost and
+/// the path to it. `heuristic` must never overestimate the remaining cost.
+pub fn astar<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (search, reached) = best_first(start, neighbours, heuristic, goal, false);
+    let reached = reached?;
+    Some((search.cost(&reached)?, search.path(&reached)?))
+}
+
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Reversed, for `BinaryHeap` to pop the lowest priority first
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+fn best_first<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+    all: bool,
+) -> (Search<S, C>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let zero = C::default();
+    let mut search = Search::new(start.clone(), zero);
+    let mut to_visit = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: zero,
+        state: start,
+    }]);
+    while let Some(Entry { cost, state, .. }) = to_visit.pop() {
+        if search.costs.get(&state).is_some_and(|best| cost > *best) {
+            continue;
+        }
+        if goal(&state) {
+            return (search, Some(state));
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            match search.costs.get(&next).map(|best| next_cost.cmp(best)) {
+                Some(Ordering::Equal) if all && next != search.start => {
+                    let parents = search.parents.entry(next).or_default();
+                    if !parents.contains(&state) {
+                        parents.push(state.clone());
+                    }
+                }
+                Some(Ordering::Equal | Ordering::Greater) => (),
+                Some(Ordering::Less) | None => {
+                    search.costs.insert(next.clone(), next_cost);
+                    search.parents.insert(next.clone(), vec![state.clone()]);
+                    to_visit.push(Entry {
+                        priority: next_cost + heuristic(&next),
+                        cost: next_cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+    (search, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -1-> 1 -1-> 3 and 0 -2-> 2 -0-> 3, 3 -5-> 4
+    fn edges(n: &u8) -> Vec<(u8, u32)> {
+        match n {
+            0 => vec![(1, 1), (2, 2)],
+            1 => vec![(3, 1)],
+            2 => vec![(3, 0)],
+            3 => vec![(4, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra(0, edges);
+        assert_eq!(search.cost(&4), Some(7));
+        assert_eq!(search.path(&4), Some(vec![0, 1, 3, 4]));
+        assert_eq!(search.on_optimal_paths([4]), HashSet::from([0, 1, 3, 4]));
+        let search = dijkstra_all(0, edges);
+        assert_eq!(search.on_optimal_paths([4]).len(), 5);
+    }
+
+    #[test]
+    fn test_bfs_astar() {
+        let search = bfs(0, |n| edges(n).into_iter().map(|e| e.0));
+        assert_eq!(search.cost(&4), Some(3));
+        let found = astar(0, edges, |n| u32::from(4 - n), |n| *n == 4);
+        assert_eq!(found, Some((7, vec![0, 1, 3, 4])));
+    }
+}
@@ -0,0 +1,359 @@
+use std::{
+    any::Any,
+    fmt::Display,
+    fs::File,
+    hint::black_box,
+    io::{self, BufRead, BufReader, Read},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::OnceLock,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    animate::Frames, cancel, params::Overrides, random::Rng, render::Scene, Answer, Params,
+    ParseError,
+};
+
+/// A day of the calendar, split into its parser and the two parts.
+pub trait Solution {
+    const DAY: u8;
+    type Data: Clone;
+    /// `()` for days without parameters.
+    type Params: Params;
+
+    /// Also parses in-memory inputs, as `parse_input(text.as_bytes())`.
+    fn parse_input(input: impl BufRead) -> Result<Self::Data, ParseError>;
+    /// Hands the parameters over to the parsed data, for days having any.
+    fn set_params(_data: &mut Self::Data, _params: &Self::Params) {}
+    fn part1(data: Self::Data) -> Answer;
+    fn part2(data: Self::Data) -> Answer;
+    /// A random input in the day's format, for the default parameters.
+    /// `size` scales it, like the number of lines or the side of the map.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+    /// Draws the state `data` is left in by `part`, for days on a map.
+    fn render(_data: Self::Data, _part: Part) -> Option<Scene> {
+        None
+    }
+    /// Replays `part` step by step, for days simulating something.
+    fn animate(_data: Self::Data, _part: Part) -> Option<Box<dyn Frames>> {
+        None
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("PART must be one of `1` or `2`, got `{s}`")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+/// Where the real input of `day` is expected, solved or not yet:
+/// `data/puzzle-N.txt`.
+pub fn input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("data/puzzle-{day}.txt"))
+}
+
+/// Type-erased [`Solution`], so the runner can hold every day in one table.
+pub struct Day {
+    pub day: u8,
+    solve: fn(&mut dyn BufRead, Part, &Overrides) -> Result<Answer, RunError>,
+    time: fn(&mut dyn BufRead, &[Part], &Overrides) -> Result<Timing, RunError>,
+    params: fn() -> Vec<(&'static str, String)>,
+    render: RenderFn,
+    animate: AnimateFn,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+type RenderFn = fn(&mut dyn BufRead, Part, &Overrides) -> Result<Option<Scene>, RunError>;
+type AnimateFn =
+    fn(&mut dyn BufRead, Part, &Overrides) -> Result<Option<Box<dyn Frames>>, RunError>;
+
+/// Wall-clock time of one parse and of each part run on its result.
+#[derive(Clone, Debug)]
+pub struct Timing {
+    pub parse: Duration,
+    pub parts: Vec<(Part, Duration)>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Day {
+            day: S::DAY,
+            solve: solve::<S>,
+            time: time::<S>,
+            params: || S::Params::default().values(),
+            render: render::<S>,
+            animate: animate::<S>,
+            generate: S::generate,
+        }
+    }
+
+    /// Name and default value of each parameter of the day.
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        (self.params)()
+    }
+
+    /// Where the real puzzle input is expected: `data/puzzle-N.txt`.
+    pub fn default_input(&self) -> PathBuf {
+        input_path(self.day)
+    }
+
+    /// Solves `part` on the file `input`, or on stdin if `input` is `-`,
+    /// `params` overriding the defaults by name.
+    pub fn solve(&self, input: &Path, part: Part, params: &Overrides) -> Result<Answer, RunError> {
+        (self.solve)(&mut open_input(input)?, part, params).map_err(|e| e.with_file(input))
+    }
+
+    /// Like [`Day::solve`], giving up with [`RunError::Timeout`] once
+    /// `timeout` is spent, see [`cancel::within`], and returning panics as
+    /// [`RunError::Panic`]. The input is read first, its reading not
+    /// counting.
+    pub fn solve_within(
+        &self,
+        input: &Path,
+        part: Part,
+        params: &Overrides,
+        timeout: Option<Duration>,
+    ) -> Result<Answer, RunError> {
+        run_within(self.solve, input, part, params, timeout)
+    }
+
+    pub fn solve_str(
+        &self,
+        input: &str,
+        part: Part,
+        params: &Overrides,
+    ) -> Result<Answer, RunError> {
+        (self.solve)(&mut input.as_bytes(), part, params)
+    }
+
+    /// The state `input` is left in by `part`, `None` for days not drawn.
+    pub fn render(
+        &self,
+        input: &Path,
+        part: Part,
+        params: &Overrides,
+    ) -> Result<Option<Scene>, RunError> {
+        (self.render)(&mut open_input(input)?, part, params).map_err(|e| e.with_file(input))
+    }
+
+    /// Like [`Day::render`], with the timeout and panic handling of
+    /// [`Day::solve_within`].
+    pub fn render_within(
+        &self,
+        input: &Path,
+        part: Part,
+        params: &Overrides,
+        timeout: Option<Duration>,
+    ) -> Result<Option<Scene>, RunError> {
+        run_within(self.render, input, part, params, timeout)
+    }
+
+    /// The frames of `part` on `input`, `None` for days not animated.
+    pub fn animate(
+        &self,
+        input: &Path,
+        part: Part,
+        params: &Overrides,
+    ) -> Result<Option<Box<dyn Frames>>, RunError> {
+        (self.animate)(&mut open_input(input)?, part, params).map_err(|e| e.with_file(input))
+    }
+
+    /// A random input seeded by `seed`, see [`Solution::generate`].
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+
+    /// Times one run of the parser then of each of `parts`, answers being
+    /// discarded. Opening `input` is not timed.
+    pub fn time(
+        &self,
+        input: &Path,
+        parts: &[Part],
+        params: &Overrides,
+    ) -> Result<Timing, RunError> {
+        (self.time)(&mut open_input(input)?, parts, params).map_err(|e| e.with_file(input))
+    }
+}
+
+/// Opens the file `input`, or stdin if `input` is `-`. Stdin is read once
+/// and kept, for every part to parse it again.
+pub fn open_input(input: &Path) -> Result<Box<dyn BufRead>, RunError> {
+    static STDIN: OnceLock<Vec<u8>> = OnceLock::new();
+    if input == Path::new("-") {
+        if let Some(stdin) = STDIN.get() {
+            return Ok(Box::new(stdin.as_slice()));
+        }
+        let mut stdin = Vec::new();
+        io::stdin()
+            .read_to_end(&mut stdin)
+            .map_err(|e| RunError::Io(input.to_owned(), e))?;
+        return Ok(Box::new(STDIN.get_or_init(|| stdin).as_slice()));
+    }
+    let file = File::open(input).map_err(|e| RunError::Io(input.to_owned(), e))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+/// What a solver panicked with, if it is text like `panic!` makes.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => (*message).to_owned(),
+        None => payload
+            .downcast_ref::<String>()
+            .cloned()
+            .unwrap_or_else(|| "a panic without a message".to_owned()),
+    }
+}
+
+/// `run` on the file `input` read beforehand, see [`Day::solve_within`].
+fn run_within<R: Send + 'static>(
+    run: fn(&mut dyn BufRead, Part, &Overrides) -> Result<R, RunError>,
+    input: &Path,
+    part: Part,
+    params: &Overrides,
+    timeout: Option<Duration>,
+) -> Result<R, RunError> {
+    let mut text = Vec::new();
+    open_input(input)?
+        .read_to_end(&mut text)
+        .map_err(|e| RunError::Io(input.to_owned(), e))?;
+    let params = params.to_vec();
+    let run = move || {
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            run(&mut text.as_slice(), part, &params)
+        }))
+    };
+    let res = match timeout {
+        Some(timeout) => cancel::within(timeout, run).ok_or(RunError::Timeout(timeout))?,
+        None => run(),
+    };
+    res.map_err(|payload| RunError::Panic(panic_message(payload.as_ref())))?
+        .map_err(|e| e.with_file(input))
+}
+
+fn parse<S: Solution>(input: &mut dyn BufRead, params: &Overrides) -> Result<S::Data, RunError> {
+    let params = S::Params::with(params).map_err(RunError::Param)?;
+    let mut data = S::parse_input(input)?;
+    S::set_params(&mut data, &params);
+    Ok(data)
+}
+
+fn solve<S: Solution>(
+    input: &mut dyn BufRead,
+    part: Part,
+    params: &Overrides,
+) -> Result<Answer, RunError> {
+    let data = parse::<S>(input, params)?;
+    Ok(match part {
+        Part::One => S::part1(data),
+        Part::Two => S::part2(data),
+    })
+}
+
+fn render<S: Solution>(
+    input: &mut dyn BufRead,
+    part: Part,
+    params: &Overrides,
+) -> Result<Option<Scene>, RunError> {
+    Ok(S::render(parse::<S>(input, params)?, part))
+}
+
+fn animate<S: Solution>(
+    input: &mut dyn BufRead,
+    part: Part,
+    params: &Overrides,
+) -> Result<Option<Box<dyn Frames>>, RunError> {
+    Ok(S::animate(parse::<S>(input, params)?, part))
+}
+
+fn time<S: Solution>(
+    input: &mut dyn BufRead,
+    parts: &[Part],
+    params: &Overrides,
+) -> Result<Timing, RunError> {
+    let params = S::Params::with(params).map_err(RunError::Param)?;
+    let start = Instant::now();
+    let mut data = S::parse_input(black_box(input))?;
+    let parse = start.elapsed();
+    S::set_params(&mut data, &params);
+    let parts = parts
+        .iter()
+        .map(|part| {
+            let data = black_box(data.clone());
+            let start = Instant::now();
+            black_box(match part {
+                Part::One => S::part1(data),
+                Part::Two => S::part2(data),
+            });
+            (*part, start.elapsed())
+        })
+        .collect();
+    Ok(Timing { parse, parts })
+}
+
+#[derive(Debug)]
+pub enum RunError {
+    Io(PathBuf, io::Error),
+    Parse(ParseError),
+    /// Unknown or invalid `--param`.
+    Param(String),
+    /// The part ran over its budget.
+    Timeout(Duration),
+    /// The solver panicked, with this message.
+    Panic(String),
+}
+
+impl RunError {
+    fn with_file(self, file: &Path) -> Self {
+        match self {
+            RunError::Parse(e) => RunError::Parse(e.with_file(file)),
+            e => e,
+        }
+    }
+}
+
+impl From<ParseError> for RunError {
+    fn from(e: ParseError) -> Self {
+        RunError::Parse(e)
+    }
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::Io(path, e) => write!(f, "Error reading `{}`: {e}", path.display()),
+            RunError::Parse(e) => e.fmt(f),
+            RunError::Param(e) => f.write_str(e),
+            RunError::Timeout(t) => write!(f, "TIMEOUT after {t:?}"),
+            RunError::Panic(message) => write!(f, "PANIC: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
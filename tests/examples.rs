@@ -0,0 +1,41 @@
+//! One test per example input `data/puzzle-N-SUFFIX.txt` and part, checking
+//! the answers listed in `data/puzzle-N-SUFFIX.expected` as lines like
+//! `part1: 42`, with the day's parameters set by lines like `param: size=11x7`.
+//! Examples without answers there are only checked to parse.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_2024::{puzzles, Part};
+
+fn input(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("data")
+        .join(name)
+}
+
+/// Checks the answer `expected` for its part, if any, else that the example
+/// parses.
+fn check(day: u8, name: &str, expected: Option<(u8, &str)>, params: &[(&str, &str)]) {
+    let day = puzzles::get(day).expect("a solution for the example's day");
+    let params: Vec<_> = params
+        .iter()
+        .map(|(n, v)| (n.to_string(), v.to_string()))
+        .collect();
+    let Some((part, expected)) = expected else {
+        if let Err(e) = day.time(&input(name), &[], &params) {
+            panic!("{e}");
+        }
+        return;
+    };
+    let part = Part::BOTH[usize::from(part) - 1];
+    let text = fs::read_to_string(input(name)).unwrap();
+    let answer = day
+        .solve_str(&text, part, &params)
+        .unwrap_or_else(|e| panic!("{name}:{e}"));
+    assert_eq!(answer.to_string(), expected, "part {part} of `{name}`");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
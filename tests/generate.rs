@@ -0,0 +1,20 @@
+//! Every day's generated inputs are solved, a seed always giving the same
+//! input.
+
+use aoc_2024::{puzzles, Part};
+
+#[test]
+fn test_generated() {
+    for day in &puzzles::ALL {
+        for seed in 0..3 {
+            let input = day.generate(seed, 12);
+            let name = format!("day {} seed {seed}", day.day);
+            assert_eq!(day.generate(seed, 12), input, "{name}");
+            for part in Part::BOTH {
+                if let Err(e) = day.solve_str(&input, part, &[]) {
+                    panic!("{name}: {e}");
+                }
+            }
+        }
+    }
+}